@@ -0,0 +1,125 @@
+//! Blocking operation without an async executor.
+//!
+//! Wrap blocking `embedded-hal` I2C, SPI and delay implementations in [`Blocking`] to use them
+//! with the driver. As these never have to wait for anything, every operation of the driver
+//! completes as soon as it is called. Use [`block_once`] to get the result of an operation in
+//! place of `.await`, e.g. from a bootloader or panic handler where no executor is running.
+//!
+//! ```rust
+//! # use core::convert::Infallible;
+//! # use embedded_hal::i2c::{ErrorType, I2c, Operation};
+//! # struct BlockingI2c;
+//! # impl ErrorType for BlockingI2c { type Error = Infallible; }
+//! # impl I2c for BlockingI2c {
+//! #     fn transaction(&mut self, _: u8, _: &mut [Operation<'_>]) -> Result<(), Infallible> {
+//! #         Ok(())
+//! #     }
+//! # }
+//! # let i2c = BlockingI2c;
+//! use ssd1306_async::{
+//!     blocking::{block_once, Blocking},
+//!     prelude::*,
+//!     I2CDisplayInterface, Ssd1306,
+//! };
+//!
+//! let interface = I2CDisplayInterface::new(Blocking::new(i2c));
+//! let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+//!     .into_buffered_graphics_mode();
+//!
+//! block_once(display.init()).unwrap().unwrap();
+//! display.set_pixel(10, 20, true);
+//! block_once(display.flush()).unwrap().unwrap();
+//! ```
+//!
+//! This module is only available with the `blocking` feature.
+
+use core::{
+    future::Future,
+    pin::pin,
+    ptr,
+    task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+};
+use embedded_hal::{delay, i2c, spi};
+use embedded_hal_async as hal;
+
+/// Adapter implementing the async `embedded-hal` traits on top of blocking implementations.
+///
+/// Supports [`I2c`](embedded_hal::i2c::I2c), [`SpiDevice`](embedded_hal::spi::SpiDevice) and
+/// [`DelayNs`](embedded_hal::delay::DelayNs).
+#[derive(Debug, Copy, Clone)]
+pub struct Blocking<T>(T);
+
+impl<T> Blocking<T> {
+    /// Wrap a blocking peripheral driver
+    pub fn new(inner: T) -> Self {
+        Self(inner)
+    }
+
+    /// Consume the adapter and return the underlying peripheral driver
+    pub fn release(self) -> T {
+        self.0
+    }
+}
+
+impl<T: i2c::ErrorType> i2c::ErrorType for Blocking<T> {
+    type Error = T::Error;
+}
+
+impl<T: i2c::I2c> hal::i2c::I2c for Blocking<T> {
+    async fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [i2c::Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        self.0.transaction(address, operations)
+    }
+}
+
+impl<T: spi::ErrorType> spi::ErrorType for Blocking<T> {
+    type Error = T::Error;
+}
+
+impl<T: spi::SpiDevice> hal::spi::SpiDevice for Blocking<T> {
+    async fn transaction(
+        &mut self,
+        operations: &mut [spi::Operation<'_, u8>],
+    ) -> Result<(), Self::Error> {
+        self.0.transaction(operations)
+    }
+}
+
+impl<T: delay::DelayNs> hal::delay::DelayNs for Blocking<T> {
+    async fn delay_ns(&mut self, ns: u32) {
+        self.0.delay_ns(ns)
+    }
+
+    async fn delay_us(&mut self, us: u32) {
+        self.0.delay_us(us)
+    }
+
+    async fn delay_ms(&mut self, ms: u32) {
+        self.0.delay_ms(ms)
+    }
+}
+
+/// Run a driver operation to completion without an executor.
+///
+/// Only operations which never have to wait, such as the driver's when used with [`Blocking`]
+/// peripherals, can be run this way. Returns `None` if the operation had to wait, e.g. for an
+/// async bus transfer, in which case it is dropped unfinished.
+pub fn block_once<F: Future>(operation: F) -> Option<F::Output> {
+    // Nothing ever needs to be woken, as the operation is only polled once
+    const VTABLE: RawWakerVTable = RawWakerVTable::new(
+        |_| RawWaker::new(ptr::null(), &VTABLE),
+        |_| {},
+        |_| {},
+        |_| {},
+    );
+    // SAFETY: The vtable functions don't use the data pointer
+    let waker = unsafe { Waker::from_raw(RawWaker::new(ptr::null(), &VTABLE)) };
+
+    match pin!(operation).poll(&mut Context::from_waker(&waker)) {
+        Poll::Ready(output) => Some(output),
+        Poll::Pending => None,
+    }
+}
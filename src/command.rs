@@ -5,8 +5,6 @@
 use crate::{DataFormat::U8, WriteOnlyDataCommand};
 
 /// SSD1306 Commands
-
-/// Commands
 #[derive(Debug, Copy, Clone)]
 #[allow(dead_code)]
 pub enum Command {
@@ -82,17 +80,30 @@ pub enum Command {
     PreChargePeriod(u8, u8),
     /// Set Vcomh Deselect level
     VcomhDeselect(VcomhLevel),
+    /// Set the raw Vcomh Deselect level byte, for controllers such as the SSD1309 and SSD1305
+    /// whose levels are encoded differently from [`VcomhLevel`]
+    VcomhDeselectRaw(u8),
     /// NOOP
     Noop,
     /// Enable charge pump
     ChargePump(bool),
     /// Select external or internal I REF. Only for 72 x 40 display with SSD1306B driver
     InternalIref(bool, bool),
+    /// Enable the DC-DC converter. Only for the SH1106, where it replaces the charge pump
+    DcDc(bool),
+    /// Select the external VCC supply in the master configuration. Only for the SSD1305
+    MasterConfig,
+    /// Fade out or blink the display. Values are the mode and the interval between brightness
+    /// steps, from 0-15 for 8 to 128 frames in steps of 8.
+    Fade(FadeMode, u8),
+    /// Double the vertical size of the displayed content. Requires the alternative COM pin
+    /// configuration.
+    Zoom(bool),
 }
 
 impl Command {
     /// Send command to SSD1306
-    pub async fn send<'a, T>(self, iface: &'a mut T) -> Result<(), T::Error>
+    pub async fn send<T>(self, iface: &mut T) -> Result<(), T::Error>
     where
         T: WriteOnlyDataCommand,
     {
@@ -160,6 +171,7 @@ impl Command {
                 2,
             ),
             Command::VcomhDeselect(level) => ([0xDB, (level as u8) << 4, 0, 0, 0, 0, 0], 2),
+            Command::VcomhDeselectRaw(level) => ([0xDB, level, 0, 0, 0, 0, 0], 2),
             Command::Noop => ([0xE3, 0, 0, 0, 0, 0, 0], 1),
             Command::ChargePump(en) => ([0x8D, 0x10 | ((en as u8) << 2), 0, 0, 0, 0, 0], 2),
             Command::InternalIref(en, current) => (
@@ -174,6 +186,13 @@ impl Command {
                 ],
                 2,
             ),
+            Command::DcDc(en) => ([0xAD, 0x8A | (en as u8), 0, 0, 0, 0, 0], 2),
+            Command::MasterConfig => ([0xAD, 0x8E, 0, 0, 0, 0, 0], 2),
+            Command::Fade(mode, interval) => (
+                [0x23, ((mode as u8) << 4) | (0xF & interval), 0, 0, 0, 0, 0],
+                2,
+            ),
+            Command::Zoom(en) => ([0xD6, en as u8, 0, 0, 0, 0, 0], 2),
         };
 
         // Send command over the interface
@@ -260,6 +279,17 @@ pub enum NFrames {
     F256 = 0b011,
 }
 
+/// Fade out and blinking mode
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FadeMode {
+    /// Fade and blink disabled
+    Disabled = 0b00,
+    /// Fade out the display until it is fully off
+    FadeOut = 0b10,
+    /// Fade out and in repeatedly
+    Blink = 0b11,
+}
+
 /// Address mode
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[allow(dead_code)]
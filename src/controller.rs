@@ -0,0 +1,110 @@
+//! Display controller.
+
+use crate::command::{Command, VcomhLevel};
+
+/// Display controller variants.
+///
+/// The driver targets the SSD1306 by default. Other controllers with a largely compatible command
+/// set can be selected with [`Ssd1306::with_controller`](crate::Ssd1306::with_controller). The
+/// selected controller adjusts the init sequence, e.g. the power supply setup and VCOMH level.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Controller {
+    /// SSD1306 with a 128x64 GDDRAM
+    #[default]
+    Ssd1306,
+    /// SH1106 with a 132x64 GDDRAM. Only page addressing is supported, so all writes are split
+    /// into pages and positioned using [`PageStart`](crate::command::Command::PageStart) and
+    /// [`ColStart`](crate::command::Command::ColStart).
+    Sh1106,
+    /// SSD1309 with a 128x64 GDDRAM. It has no charge pump and requires an external VCC supply.
+    Ssd1309,
+    /// SSD1305 with a 132x64 GDDRAM, powered from an external VCC supply. Panels always use the
+    /// alternative COM pin configuration.
+    Ssd1305,
+    /// SSD1315 with a 128x64 GDDRAM. A drop-in replacement for the SSD1306, but with different
+    /// VCOMH deselect levels.
+    Ssd1315,
+}
+
+impl Controller {
+    /// Number of columns in the GDDRAM of the controller
+    pub const fn columns(self) -> u8 {
+        match self {
+            Controller::Ssd1306 | Controller::Ssd1309 | Controller::Ssd1315 => 128,
+            Controller::Sh1106 | Controller::Ssd1305 => 132,
+        }
+    }
+
+    /// Whether the controller supports the horizontal and vertical addressing modes
+    pub const fn supports_addr_modes(self) -> bool {
+        !matches!(self, Controller::Sh1106)
+    }
+
+    /// Whether the controller supports continuous hardware scrolling
+    pub const fn supports_scrolling(self) -> bool {
+        !matches!(self, Controller::Sh1106)
+    }
+
+    /// Whether the controller supports the fade out, blinking and zoom in commands
+    pub const fn supports_fade_and_zoom(self) -> bool {
+        matches!(self, Controller::Ssd1306 | Controller::Ssd1315)
+    }
+
+    /// Whether the controller has an internal charge pump, controlled by
+    /// [`ChargePump`](crate::command::Command::ChargePump)
+    pub const fn has_charge_pump(self) -> bool {
+        matches!(self, Controller::Ssd1306 | Controller::Ssd1315)
+    }
+
+    /// Whether the controller can select an internal IREF, see
+    /// [`InternalIref`](crate::command::Command::InternalIref)
+    pub const fn supports_int_iref(self) -> bool {
+        matches!(self, Controller::Ssd1306 | Controller::Ssd1315)
+    }
+
+    /// Number of GDDRAM columns on either side of a panel sized for 128 columns.
+    ///
+    /// Panels for controllers with wider GDDRAM are centered, e.g. 128px wide SH1106 panels start
+    /// at column 2.
+    pub const fn column_offset(self) -> u8 {
+        (self.columns() - 128) / 2
+    }
+
+    /// Command setting up the panel power supply during init, if any
+    pub(crate) const fn power_supply(self, charge_pump: bool) -> Option<Command> {
+        match self {
+            Controller::Ssd1306 | Controller::Ssd1315 => Some(Command::ChargePump(charge_pump)),
+            Controller::Sh1106 => Some(Command::DcDc(charge_pump)),
+            Controller::Ssd1305 => Some(Command::MasterConfig),
+            Controller::Ssd1309 => None,
+        }
+    }
+
+    /// Phase 1 of the precharge period, in DCLKs
+    pub(crate) const fn precharge_phase1(self) -> u8 {
+        match self {
+            // Reset values of the externally supplied controllers
+            Controller::Ssd1309 | Controller::Ssd1305 => 2,
+            Controller::Ssd1306 | Controller::Sh1106 | Controller::Ssd1315 => 1,
+        }
+    }
+
+    /// VCOMH deselect level sent during init
+    pub(crate) const fn vcomh_deselect(self) -> Command {
+        match self {
+            Controller::Ssd1306 | Controller::Sh1106 => Command::VcomhDeselect(VcomhLevel::Auto),
+            Controller::Ssd1315 => Command::VcomhDeselect(VcomhLevel::V077),
+            // ~0.78 * Vcc, these controllers use a different encoding of the level
+            Controller::Ssd1309 | Controller::Ssd1305 => Command::VcomhDeselectRaw(0x34),
+        }
+    }
+
+    /// COM pins hardware configuration, given the one preferred by the display size
+    pub(crate) const fn com_pin_cfg(self, size_cfg: Command) -> Command {
+        match self {
+            Controller::Ssd1305 => Command::ComPinConfig(true, false),
+            _ => size_cfg,
+        }
+    }
+}
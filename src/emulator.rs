@@ -0,0 +1,592 @@
+//! Host-side SSD1306 emulator.
+//!
+//! [`EmulatedDisplay`] implements [`WriteOnlyDataCommand`] and decodes the command and data
+//! stream a real controller would receive. It keeps a model of the GDDRAM (128x64, or 132x64 for
+//! the SH1106 and SSD1305) along with the addressing and display configuration registers, and can
+//! render what the panel would show. This makes it possible to test drawing code without any
+//! hardware attached.
+//!
+//! ```rust
+//! use embassy_futures::block_on;
+//! use ssd1306_async::{emulator::EmulatedDisplay, prelude::*, Ssd1306};
+//!
+//! let mut display = Ssd1306::new(
+//!     EmulatedDisplay::new(),
+//!     DisplaySize128x64,
+//!     DisplayRotation::Rotate0,
+//! )
+//! .into_buffered_graphics_mode();
+//!
+//! block_on(display.init()).unwrap();
+//! display.set_pixel(3, 5, true);
+//! block_on(display.flush()).unwrap();
+//!
+//! let frame = display.release().frame();
+//! assert!(frame.pixel(3, 5));
+//! ```
+//!
+//! This module is only available with the `emulator` feature, which requires `std`.
+
+use crate::{
+    command::AddrMode, controller::Controller, DataFormat, DisplayError, WriteOnlyDataCommand,
+};
+use core::fmt;
+use std::{string::String, vec, vec::Vec};
+
+/// Maximum number of columns in the GDDRAM of any supported controller
+const MAX_COLUMNS: usize = 132;
+
+/// Number of 8px pages in the GDDRAM
+const PAGES: usize = 8;
+
+/// Number of rows (COM lines) in the GDDRAM
+const ROWS: usize = PAGES * 8;
+
+/// An emulated SSD1306 controller.
+///
+/// Commands are decoded as they are received. Data bytes are written to the GDDRAM model at the
+/// current address pointer, which then advances according to the selected addressing mode.
+///
+/// The GDDRAM is zeroed on creation, whereas on real hardware it contains random data after power
+/// up.
+///
+/// Commands which the emulated controller does not support, such as addressing mode changes on
+/// the SH1106 or charge pump setup on the SSD1309, cause a panic.
+#[derive(Clone)]
+pub struct EmulatedDisplay {
+    controller: Controller,
+    gddram: [[u8; MAX_COLUMNS]; PAGES],
+    pending: Vec<u8>,
+    addr_mode: AddrMode,
+    col_start: u8,
+    col_end: u8,
+    page_start: u8,
+    page_end: u8,
+    col: u8,
+    page: u8,
+    segment_remap: bool,
+    reverse_com_dir: bool,
+    start_line: u8,
+    display_offset: u8,
+    multiplex: u8,
+    com_pin_cfg: u8,
+    clock_div: u8,
+    precharge: u8,
+    vcomh: u8,
+    contrast: u8,
+    charge_pump: bool,
+    inverted: bool,
+    all_on: bool,
+    display_on: bool,
+    scroll_enabled: bool,
+    scroll_setup: Vec<u8>,
+    vertical_scroll_area: (u8, u8),
+    fade: u8,
+    zoom: bool,
+}
+
+impl Default for EmulatedDisplay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EmulatedDisplay {
+    /// Create a new emulated SSD1306 in its power-on reset state.
+    pub fn new() -> Self {
+        Self::new_with_controller(Controller::Ssd1306)
+    }
+
+    /// Create a new emulated controller of the given variant in its power-on reset state.
+    pub fn new_with_controller(controller: Controller) -> Self {
+        Self {
+            controller,
+            gddram: [[0; MAX_COLUMNS]; PAGES],
+            pending: Vec::new(),
+            addr_mode: AddrMode::Page,
+            col_start: 0,
+            col_end: controller.columns() - 1,
+            page_start: 0,
+            page_end: PAGES as u8 - 1,
+            col: 0,
+            page: 0,
+            segment_remap: false,
+            reverse_com_dir: false,
+            start_line: 0,
+            display_offset: 0,
+            multiplex: ROWS as u8 - 1,
+            com_pin_cfg: 0x12,
+            clock_div: 0x80,
+            precharge: 0x22,
+            vcomh: 0x20,
+            contrast: 0x7F,
+            charge_pump: false,
+            inverted: false,
+            all_on: false,
+            display_on: false,
+            scroll_enabled: false,
+            scroll_setup: Vec::new(),
+            vertical_scroll_area: (0, ROWS as u8),
+            fade: 0,
+            zoom: false,
+        }
+    }
+
+    /// The emulated controller variant.
+    pub fn controller(&self) -> Controller {
+        self.controller
+    }
+
+    /// The raw GDDRAM contents of a page, indexed by column.
+    pub fn ram(&self, page: u8) -> &[u8] {
+        &self.gddram[page as usize][..self.columns()]
+    }
+
+    /// Number of columns in the GDDRAM
+    fn columns(&self) -> usize {
+        self.controller.columns() as usize
+    }
+
+    /// The currently selected addressing mode.
+    pub fn addr_mode(&self) -> AddrMode {
+        self.addr_mode
+    }
+
+    /// The current address pointer as (column, page).
+    pub fn address(&self) -> (u8, u8) {
+        (self.col, self.page)
+    }
+
+    /// Whether the display is switched on.
+    pub fn is_on(&self) -> bool {
+        self.display_on
+    }
+
+    /// Whether the display output is inverted.
+    pub fn is_inverted(&self) -> bool {
+        self.inverted
+    }
+
+    /// Whether all pixels are forced on, regardless of the GDDRAM contents.
+    pub fn is_all_on(&self) -> bool {
+        self.all_on
+    }
+
+    /// Whether the last column is mapped to SEG0.
+    pub fn segment_remap(&self) -> bool {
+        self.segment_remap
+    }
+
+    /// Whether the COM outputs are scanned from COM[N-1] to COM0.
+    pub fn reverse_com_dir(&self) -> bool {
+        self.reverse_com_dir
+    }
+
+    /// The display start line, 0-63.
+    pub fn start_line(&self) -> u8 {
+        self.start_line
+    }
+
+    /// The vertical display offset, 0-63.
+    pub fn display_offset(&self) -> u8 {
+        self.display_offset
+    }
+
+    /// The multiplex ratio (number of active rows) minus one.
+    pub fn multiplex(&self) -> u8 {
+        self.multiplex
+    }
+
+    /// The raw COM pins hardware configuration byte.
+    pub fn com_pin_cfg(&self) -> u8 {
+        self.com_pin_cfg
+    }
+
+    /// The raw display clock divide ratio/oscillator frequency byte.
+    pub fn clock_div(&self) -> u8 {
+        self.clock_div
+    }
+
+    /// The raw precharge period byte.
+    pub fn precharge(&self) -> u8 {
+        self.precharge
+    }
+
+    /// The raw VCOMH deselect level byte.
+    pub fn vcomh(&self) -> u8 {
+        self.vcomh
+    }
+
+    /// The contrast setting.
+    pub fn contrast(&self) -> u8 {
+        self.contrast
+    }
+
+    /// Whether the internal charge pump (the DC-DC converter on the SH1106) is enabled.
+    pub fn charge_pump(&self) -> bool {
+        self.charge_pump
+    }
+
+    /// Whether hardware scrolling is active.
+    pub fn scroll_enabled(&self) -> bool {
+        self.scroll_enabled
+    }
+
+    /// The raw bytes of the last continuous scroll setup command, or an empty slice if scrolling
+    /// has not been set up.
+    pub fn scroll_setup(&self) -> &[u8] {
+        &self.scroll_setup
+    }
+
+    /// The vertical scroll area as (number of fixed top rows, number of scrolled rows).
+    pub fn vertical_scroll_area(&self) -> (u8, u8) {
+        self.vertical_scroll_area
+    }
+
+    /// The raw fade out and blinking configuration byte.
+    pub fn fade(&self) -> u8 {
+        self.fade
+    }
+
+    /// Whether zoom in is enabled.
+    pub fn zoom(&self) -> bool {
+        self.zoom
+    }
+
+    /// Whether the panel pixel at `(x, y)` is lit.
+    ///
+    /// Coordinates are given as seen on a module mounted the usual way round, where segment remap
+    /// and reverse COM scan direction (the driver's `Rotate0` setup) show the GDDRAM upright. The
+    /// panel is as wide as the GDDRAM and as many rows high as the multiplex ratio selects. Pixels
+    /// outside of that area are never lit.
+    pub fn pixel(&self, x: usize, y: usize) -> bool {
+        let columns = self.columns();
+        let rows = self.multiplex as usize + 1;
+
+        if !self.display_on || x >= columns || y >= rows {
+            return false;
+        }
+
+        if self.all_on {
+            return true;
+        }
+
+        let col = if self.segment_remap {
+            x
+        } else {
+            columns - 1 - x
+        };
+        let line = if self.reverse_com_dir {
+            y
+        } else {
+            rows - 1 - y
+        };
+        // Zoom in shows every row of the first half twice
+        let line = if self.zoom { line / 2 } else { line };
+        let row = (line + self.start_line as usize + self.display_offset as usize) % ROWS;
+
+        let lit = self.gddram[row / 8][col] & (1 << (row % 8)) != 0;
+
+        lit ^ self.inverted
+    }
+
+    /// Render the whole panel output.
+    ///
+    /// See [`pixel`](Self::pixel) for the coordinate system used.
+    pub fn frame(&self) -> Frame {
+        let mut frame = Frame::new(self.columns(), self.multiplex as usize + 1);
+
+        for y in 0..frame.height() {
+            for x in 0..frame.width() {
+                frame.set_pixel(x, y, self.pixel(x, y));
+            }
+        }
+
+        frame
+    }
+
+    fn command(&mut self, byte: u8) {
+        self.pending.push(byte);
+
+        if self.pending.len() < Self::command_len(self.pending[0]) {
+            return;
+        }
+
+        let cmd = core::mem::take(&mut self.pending);
+
+        let unsupported = match cmd[0] {
+            0x20..=0x22 => !self.controller.supports_addr_modes(),
+            0x26..=0x2F | 0xA3 => !self.controller.supports_scrolling(),
+            0x8D => !self.controller.has_charge_pump(),
+            0x23 | 0xD6 => !self.controller.supports_fade_and_zoom(),
+            0xAD => self.controller == Controller::Ssd1309,
+            _ => false,
+        };
+        if unsupported {
+            panic!(
+                "Unsupported command byte {:#04X} for {:?}",
+                cmd[0], self.controller
+            );
+        }
+
+        match cmd[0] {
+            0x00..=0x0F => self.col = (self.col & 0xF0) | (cmd[0] & 0xF),
+            0x10..=0x1F => self.col = (self.col & 0x0F) | ((cmd[0] & 0xF) << 4),
+            0x20 => {
+                self.addr_mode = match cmd[1] & 0b11 {
+                    0b00 => AddrMode::Horizontal,
+                    0b01 => AddrMode::Vertical,
+                    _ => AddrMode::Page,
+                }
+            }
+            0x21 => {
+                // Wider GDDRAMs use all bits of the column address
+                let mask = if self.columns() > 128 { 0xFF } else { 0x7F };
+                self.col_start = cmd[1] & mask;
+                self.col_end = cmd[2] & mask;
+                self.col = self.col_start;
+            }
+            0x22 => {
+                self.page_start = cmd[1] & 0x7;
+                self.page_end = cmd[2] & 0x7;
+                self.page = self.page_start;
+            }
+            // Scroll setup is recorded, but scrolling itself is not emulated
+            0x26 | 0x27 | 0x29 | 0x2A => self.scroll_setup = cmd,
+            0xA3 => self.vertical_scroll_area = (cmd[1] & 0x3F, cmd[2] & 0x7F),
+            0x23 => self.fade = cmd[1] & 0x3F,
+            0x2E | 0x2F => self.scroll_enabled = cmd[0] & 1 != 0,
+            // SH1106 charge pump voltage
+            0x30..=0x33 if self.controller == Controller::Sh1106 => {}
+            0x40..=0x7F => self.start_line = cmd[0] & 0x3F,
+            0x81 => self.contrast = cmd[1],
+            0x8D => self.charge_pump = cmd[1] & 0x04 != 0,
+            0xA0 | 0xA1 => self.segment_remap = cmd[0] & 1 != 0,
+            0xA4 | 0xA5 => self.all_on = cmd[0] & 1 != 0,
+            0xA6 | 0xA7 => self.inverted = cmd[0] & 1 != 0,
+            0xA8 => self.multiplex = cmd[1] & 0x3F,
+            // DC-DC converter on the SH1106, external VCC selection on the SSD1305 and IREF
+            // selection otherwise
+            0xAD if self.controller == Controller::Sh1106 => self.charge_pump = cmd[1] & 1 != 0,
+            0xAD if self.controller == Controller::Ssd1305 => self.charge_pump = false,
+            0xAD => {}
+            0xAE | 0xAF => self.display_on = cmd[0] & 1 != 0,
+            0xB0..=0xB7 => self.page = cmd[0] & 0x7,
+            0xC0 | 0xC8 => self.reverse_com_dir = cmd[0] & 0x08 != 0,
+            0xD3 => self.display_offset = cmd[1] & 0x3F,
+            0xD5 => self.clock_div = cmd[1],
+            0xD6 => self.zoom = cmd[1] & 1 != 0,
+            0xD9 => self.precharge = cmd[1],
+            0xDA => self.com_pin_cfg = cmd[1],
+            0xDB => self.vcomh = cmd[1],
+            0xE3 => {}
+            other => panic!("Unsupported command byte {:#04X}", other),
+        }
+    }
+
+    /// Total length in bytes of the command starting with `first`
+    fn command_len(first: u8) -> usize {
+        match first {
+            0x26 | 0x27 => 7,
+            0x29 | 0x2A => 6,
+            0x21 | 0x22 | 0xA3 => 3,
+            0x20 | 0x23 | 0x81 | 0x8D | 0xA8 | 0xAD | 0xD3 | 0xD5 | 0xD6 | 0xD9 | 0xDA | 0xDB => 2,
+            _ => 1,
+        }
+    }
+
+    fn data(&mut self, byte: u8) {
+        if (self.col as usize) < self.columns() {
+            self.gddram[self.page as usize][self.col as usize] = byte;
+        }
+
+        match self.addr_mode {
+            AddrMode::Horizontal => {
+                if self.col >= self.col_end {
+                    self.col = self.col_start;
+                    self.page = if self.page >= self.page_end {
+                        self.page_start
+                    } else {
+                        self.page + 1
+                    };
+                } else {
+                    self.col += 1;
+                }
+            }
+            AddrMode::Vertical => {
+                if self.page >= self.page_end {
+                    self.page = self.page_start;
+                    self.col = if self.col >= self.col_end {
+                        self.col_start
+                    } else {
+                        self.col + 1
+                    };
+                } else {
+                    self.page += 1;
+                }
+            }
+            AddrMode::Page => {
+                // The column pointer wraps around, but the page pointer stays put
+                self.col = if self.col as usize >= self.columns() - 1 {
+                    0
+                } else {
+                    self.col + 1
+                };
+            }
+        }
+    }
+}
+
+impl WriteOnlyDataCommand for EmulatedDisplay {
+    type Error = DisplayError;
+
+    async fn send_commands(&mut self, cmds: DataFormat<'_>) -> Result<(), Self::Error> {
+        match cmds {
+            DataFormat::U8(slice) => {
+                for &byte in slice {
+                    self.command(byte);
+                }
+                Ok(())
+            }
+            _ => Err(DisplayError::DataFormatNotImplemented),
+        }
+    }
+
+    async fn send_data(&mut self, buf: DataFormat<'_>) -> Result<(), Self::Error> {
+        match buf {
+            DataFormat::U8(slice) => {
+                for &byte in slice {
+                    self.data(byte);
+                }
+                Ok(())
+            }
+            DataFormat::U8Iter(iter) => {
+                for byte in iter {
+                    self.data(byte);
+                }
+                Ok(())
+            }
+            _ => Err(DisplayError::DataFormatNotImplemented),
+        }
+    }
+}
+
+/// A monochrome image, as rendered by [`EmulatedDisplay::frame`].
+///
+/// Frames can be converted from and to the plain (`P1`) [PBM] format to store reference images.
+///
+/// [PBM]: https://netpbm.sourceforge.net/doc/pbm.html
+#[derive(Clone, PartialEq, Eq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    pixels: Vec<bool>,
+}
+
+impl Frame {
+    /// Create a new frame with all pixels off.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![false; width * height],
+        }
+    }
+
+    /// Width in pixels
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Height in pixels
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether the pixel at `(x, y)` is lit. Pixels outside of the frame are never lit.
+    pub fn pixel(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height && self.pixels[y * self.width + x]
+    }
+
+    /// Turn a pixel on or off. Pixels outside of the frame are ignored.
+    pub fn set_pixel(&mut self, x: usize, y: usize, value: bool) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = value;
+        }
+    }
+
+    /// Copy out the area of `width` x `height` pixels starting at `(x, y)`.
+    pub fn crop(&self, x: usize, y: usize, width: usize, height: usize) -> Frame {
+        let mut cropped = Frame::new(width, height);
+
+        for cy in 0..height {
+            for cx in 0..width {
+                cropped.set_pixel(cx, cy, self.pixel(x + cx, y + cy));
+            }
+        }
+
+        cropped
+    }
+
+    /// Encode the frame as a plain (`P1`) PBM image. Lit pixels are stored as `1`.
+    pub fn to_pbm(&self) -> String {
+        let mut pbm = std::format!("P1\n{} {}\n", self.width, self.height);
+
+        for row in self.pixels.chunks(self.width.max(1)) {
+            for &lit in row {
+                pbm.push(if lit { '1' } else { '0' });
+            }
+            pbm.push('\n');
+        }
+
+        pbm
+    }
+
+    /// Decode a plain (`P1`) PBM image. Returns `None` if the input is not a valid image.
+    pub fn from_pbm(pbm: &str) -> Option<Frame> {
+        // Comments run from `#` to the end of the line
+        let mut tokens = pbm
+            .lines()
+            .map(|line| line.split('#').next().unwrap_or(""))
+            .flat_map(str::split_whitespace);
+
+        if tokens.next()? != "P1" {
+            return None;
+        }
+
+        let width = tokens.next()?.parse().ok()?;
+        let height = tokens.next()?.parse().ok()?;
+
+        let mut frame = Frame::new(width, height);
+        let mut bits = tokens.flat_map(str::chars);
+
+        for pixel in frame.pixels.iter_mut() {
+            *pixel = match bits.next()? {
+                '0' => false,
+                '1' => true,
+                _ => return None,
+            };
+        }
+
+        if bits.next().is_some() {
+            return None;
+        }
+
+        Some(frame)
+    }
+}
+
+impl fmt::Debug for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Frame {}x{}", self.width, self.height)?;
+
+        for row in self.pixels.chunks(self.width.max(1)) {
+            for &lit in row {
+                f.write_str(if lit { "#" } else { "." })?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
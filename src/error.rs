@@ -1,5 +1,6 @@
 /// Errors in this crate
 #[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Error<CommE, PinE> {
     /// Communication error
     Comm(CommE),
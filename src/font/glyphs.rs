@@ -0,0 +1,1355 @@
+//! Glyph tables of the built-in fonts.
+//!
+//! The glyphs are taken from the public domain X11 misc-fixed fonts. Every table contains the
+//! printable ASCII characters followed by the printable ISO 8859-1 (Latin-1) characters, starting
+//! at U+00A0.
+
+/// Glyphs of the 5x8 font, including one column of spacing on the right
+pub(super) static GLYPHS_5X8: [[u8; 5]; 191] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00], // ' '
+    [0x00, 0x00, 0x5e, 0x00, 0x00], // '!'
+    [0x00, 0x0e, 0x00, 0x0e, 0x00], // '"'
+    [0x14, 0x7f, 0x14, 0x7f, 0x14], // '#'
+    [0x04, 0x2a, 0x7f, 0x2a, 0x10], // '$'
+    [0x00, 0x16, 0x08, 0x34, 0x00], // '%'
+    [0x36, 0x49, 0x36, 0x40, 0x00], // '&'
+    [0x00, 0x00, 0x0e, 0x00, 0x00], // '\''
+    [0x00, 0x3c, 0x42, 0x00, 0x00], // '('
+    [0x00, 0x42, 0x3c, 0x00, 0x00], // ')'
+    [0x54, 0x38, 0x38, 0x54, 0x00], // '*'
+    [0x10, 0x10, 0x7c, 0x10, 0x10], // '+'
+    [0x00, 0x80, 0x60, 0x20, 0x00], // ','
+    [0x10, 0x10, 0x10, 0x10, 0x00], // '-'
+    [0x00, 0x40, 0xe0, 0x40, 0x00], // '.'
+    [0x60, 0x10, 0x08, 0x06, 0x00], // '/'
+    [0x00, 0x3c, 0x42, 0x3c, 0x00], // '0'
+    [0x00, 0x44, 0x7e, 0x40, 0x00], // '1'
+    [0x64, 0x52, 0x52, 0x4c, 0x00], // '2'
+    [0x22, 0x4a, 0x4e, 0x32, 0x00], // '3'
+    [0x18, 0x14, 0x7e, 0x10, 0x00], // '4'
+    [0x2e, 0x4a, 0x4a, 0x32, 0x00], // '5'
+    [0x3c, 0x4a, 0x4a, 0x30, 0x00], // '6'
+    [0x02, 0x62, 0x1a, 0x06, 0x00], // '7'
+    [0x34, 0x4a, 0x4a, 0x34, 0x00], // '8'
+    [0x0c, 0x52, 0x52, 0x3c, 0x00], // '9'
+    [0x00, 0x6c, 0x6c, 0x00, 0x00], // ':'
+    [0x00, 0x80, 0x6c, 0x2c, 0x00], // ';'
+    [0x00, 0x18, 0x24, 0x42, 0x00], // '<'
+    [0x28, 0x28, 0x28, 0x28, 0x00], // '='
+    [0x00, 0x42, 0x24, 0x18, 0x00], // '>'
+    [0x00, 0x04, 0x52, 0x0c, 0x00], // '?'
+    [0x3c, 0x42, 0x99, 0xa5, 0x1e], // '@'
+    [0x7c, 0x12, 0x12, 0x7c, 0x00], // 'A'
+    [0x7e, 0x4a, 0x4a, 0x34, 0x00], // 'B'
+    [0x3c, 0x42, 0x42, 0x24, 0x00], // 'C'
+    [0x7e, 0x42, 0x42, 0x3c, 0x00], // 'D'
+    [0x7e, 0x4a, 0x4a, 0x42, 0x00], // 'E'
+    [0x7e, 0x0a, 0x0a, 0x02, 0x00], // 'F'
+    [0x3c, 0x42, 0x52, 0x34, 0x00], // 'G'
+    [0x7e, 0x08, 0x08, 0x7e, 0x00], // 'H'
+    [0x00, 0x42, 0x7e, 0x42, 0x00], // 'I'
+    [0x20, 0x42, 0x3e, 0x02, 0x00], // 'J'
+    [0x7e, 0x08, 0x34, 0x42, 0x00], // 'K'
+    [0x7e, 0x40, 0x40, 0x40, 0x00], // 'L'
+    [0x7e, 0x0c, 0x0c, 0x7e, 0x00], // 'M'
+    [0x7e, 0x0c, 0x38, 0x7e, 0x00], // 'N'
+    [0x3c, 0x42, 0x42, 0x3c, 0x00], // 'O'
+    [0x7e, 0x12, 0x12, 0x0c, 0x00], // 'P'
+    [0x3c, 0x52, 0x62, 0xbc, 0x00], // 'Q'
+    [0x7e, 0x12, 0x12, 0x6c, 0x00], // 'R'
+    [0x24, 0x4a, 0x52, 0x24, 0x00], // 'S'
+    [0x00, 0x02, 0x7e, 0x02, 0x00], // 'T'
+    [0x3e, 0x40, 0x40, 0x3e, 0x00], // 'U'
+    [0x1e, 0x60, 0x60, 0x1e, 0x00], // 'V'
+    [0x7e, 0x30, 0x30, 0x7e, 0x00], // 'W'
+    [0x66, 0x18, 0x18, 0x66, 0x00], // 'X'
+    [0x06, 0x08, 0x70, 0x08, 0x06], // 'Y'
+    [0x62, 0x52, 0x4a, 0x46, 0x00], // 'Z'
+    [0x00, 0x7e, 0x42, 0x42, 0x00], // '['
+    [0x06, 0x08, 0x10, 0x60, 0x00], // '\\'
+    [0x00, 0x42, 0x42, 0x7e, 0x00], // ']'
+    [0x00, 0x04, 0x02, 0x04, 0x00], // '^'
+    [0x80, 0x80, 0x80, 0x80, 0x00], // '_'
+    [0x00, 0x02, 0x04, 0x00, 0x00], // '`'
+    [0x30, 0x48, 0x48, 0x78, 0x00], // 'a'
+    [0x7e, 0x48, 0x48, 0x30, 0x00], // 'b'
+    [0x00, 0x30, 0x48, 0x48, 0x00], // 'c'
+    [0x30, 0x48, 0x48, 0x7e, 0x00], // 'd'
+    [0x30, 0x68, 0x58, 0x10, 0x00], // 'e'
+    [0x10, 0x7c, 0x12, 0x04, 0x00], // 'f'
+    [0x10, 0xa8, 0xa8, 0x70, 0x00], // 'g'
+    [0x7e, 0x08, 0x08, 0x70, 0x00], // 'h'
+    [0x00, 0x48, 0x7a, 0x40, 0x00], // 'i'
+    [0x00, 0x40, 0x80, 0x7a, 0x00], // 'j'
+    [0x7e, 0x10, 0x10, 0x68, 0x00], // 'k'
+    [0x00, 0x42, 0x7e, 0x40, 0x00], // 'l'
+    [0x78, 0x08, 0x70, 0x08, 0x70], // 'm'
+    [0x78, 0x08, 0x08, 0x70, 0x00], // 'n'
+    [0x30, 0x48, 0x48, 0x30, 0x00], // 'o'
+    [0xf8, 0x28, 0x28, 0x10, 0x00], // 'p'
+    [0x10, 0x28, 0x28, 0xf8, 0x00], // 'q'
+    [0x78, 0x10, 0x08, 0x10, 0x00], // 'r'
+    [0x00, 0x50, 0x58, 0x28, 0x00], // 's'
+    [0x08, 0x3e, 0x48, 0x20, 0x00], // 't'
+    [0x38, 0x40, 0x40, 0x78, 0x00], // 'u'
+    [0x00, 0x38, 0x40, 0x38, 0x00], // 'v'
+    [0x38, 0x40, 0x30, 0x40, 0x38], // 'w'
+    [0x48, 0x30, 0x30, 0x48, 0x00], // 'x'
+    [0x58, 0xa0, 0xa0, 0x78, 0x00], // 'y'
+    [0x48, 0x68, 0x58, 0x48, 0x00], // 'z'
+    [0x08, 0x2a, 0x55, 0x41, 0x00], // '{'
+    [0x00, 0x00, 0x7e, 0x00, 0x00], // '|'
+    [0x41, 0x55, 0x2a, 0x08, 0x00], // '}'
+    [0x04, 0x02, 0x04, 0x02, 0x00], // '~'
+    [0x00, 0x00, 0x00, 0x00, 0x00], // '\u{a0}'
+    [0x00, 0x00, 0x7a, 0x00, 0x00], // '¡'
+    [0x30, 0x48, 0xfc, 0x48, 0x00], // '¢'
+    [0x48, 0x3c, 0x4a, 0x24, 0x00], // '£'
+    [0x44, 0x38, 0x28, 0x38, 0x44], // '¤'
+    [0x2a, 0x2c, 0x78, 0x2c, 0x2a], // '¥'
+    [0x00, 0x00, 0x77, 0x00, 0x00], // '¦'
+    [0x4e, 0x55, 0x55, 0x39, 0x00], // '§'
+    [0x00, 0x02, 0x00, 0x02, 0x00], // '¨'
+    [0x3c, 0x5a, 0x66, 0x42, 0x3c], // '©'
+    [0x00, 0x12, 0x15, 0x17, 0x00], // 'ª'
+    [0x10, 0x28, 0x10, 0x28, 0x00], // '«'
+    [0x00, 0x10, 0x10, 0x70, 0x00], // '¬'
+    [0x00, 0x10, 0x10, 0x10, 0x00], // '\u{ad}'
+    [0x3c, 0x7e, 0x56, 0x6a, 0x3c], // '®'
+    [0x00, 0x02, 0x02, 0x02, 0x00], // '¯'
+    [0x00, 0x04, 0x0a, 0x04, 0x00], // '°'
+    [0x00, 0x48, 0x5c, 0x48, 0x00], // '±'
+    [0x00, 0x12, 0x19, 0x16, 0x00], // '²'
+    [0x00, 0x15, 0x15, 0x0a, 0x00], // '³'
+    [0x00, 0x04, 0x02, 0x00, 0x00], // '´'
+    [0xf8, 0x40, 0x40, 0x38, 0x00], // 'µ'
+    [0x0c, 0x1e, 0x7e, 0x02, 0x7e], // '¶'
+    [0x00, 0x00, 0x10, 0x00, 0x00], // '·'
+    [0x00, 0x80, 0x40, 0x00, 0x00], // '¸'
+    [0x00, 0x12, 0x1f, 0x10, 0x00], // '¹'
+    [0x00, 0x12, 0x15, 0x12, 0x00], // 'º'
+    [0x28, 0x10, 0x28, 0x10, 0x00], // '»'
+    [0x2f, 0x30, 0x78, 0x20, 0x00], // '¼'
+    [0x0f, 0x48, 0x64, 0x58, 0x00], // '½'
+    [0x35, 0x2a, 0x78, 0x20, 0x00], // '¾'
+    [0x00, 0x30, 0x4a, 0x20, 0x00], // '¿'
+    [0x78, 0x15, 0x16, 0x78, 0x00], // 'À'
+    [0x78, 0x16, 0x15, 0x78, 0x00], // 'Á'
+    [0x7a, 0x15, 0x15, 0x7a, 0x00], // 'Â'
+    [0x7a, 0x15, 0x16, 0x79, 0x00], // 'Ã'
+    [0x79, 0x14, 0x14, 0x79, 0x00], // 'Ä'
+    [0x7a, 0x15, 0x15, 0x7a, 0x00], // 'Å'
+    [0x7c, 0x12, 0x7e, 0x52, 0x00], // 'Æ'
+    [0x3c, 0xc2, 0x42, 0x24, 0x00], // 'Ç'
+    [0x7c, 0x55, 0x56, 0x44, 0x00], // 'È'
+    [0x7c, 0x56, 0x55, 0x44, 0x00], // 'É'
+    [0x7e, 0x55, 0x55, 0x46, 0x00], // 'Ê'
+    [0x7d, 0x54, 0x54, 0x45, 0x00], // 'Ë'
+    [0x00, 0x45, 0x7e, 0x44, 0x00], // 'Ì'
+    [0x00, 0x44, 0x7e, 0x45, 0x00], // 'Í'
+    [0x00, 0x46, 0x7d, 0x46, 0x00], // 'Î'
+    [0x00, 0x45, 0x7c, 0x45, 0x00], // 'Ï'
+    [0x08, 0x7e, 0x4a, 0x42, 0x3c], // 'Ð'
+    [0x7e, 0x09, 0x12, 0x7d, 0x00], // 'Ñ'
+    [0x38, 0x45, 0x46, 0x38, 0x00], // 'Ò'
+    [0x38, 0x46, 0x45, 0x38, 0x00], // 'Ó'
+    [0x3a, 0x45, 0x45, 0x3a, 0x00], // 'Ô'
+    [0x3a, 0x45, 0x46, 0x39, 0x00], // 'Õ'
+    [0x39, 0x44, 0x44, 0x39, 0x00], // 'Ö'
+    [0x00, 0x50, 0x20, 0x50, 0x00], // '×'
+    [0x7c, 0x72, 0x4e, 0x3e, 0x00], // 'Ø'
+    [0x3c, 0x41, 0x42, 0x3c, 0x00], // 'Ù'
+    [0x3c, 0x42, 0x41, 0x3c, 0x00], // 'Ú'
+    [0x3e, 0x41, 0x41, 0x3e, 0x00], // 'Û'
+    [0x3d, 0x40, 0x40, 0x3d, 0x00], // 'Ü'
+    [0x04, 0x08, 0x72, 0x09, 0x04], // 'Ý'
+    [0x7e, 0x24, 0x24, 0x18, 0x00], // 'Þ'
+    [0x7c, 0x02, 0x5a, 0x24, 0x00], // 'ß'
+    [0x30, 0x49, 0x4a, 0x78, 0x00], // 'à'
+    [0x30, 0x4a, 0x49, 0x78, 0x00], // 'á'
+    [0x30, 0x4a, 0x49, 0x7a, 0x00], // 'â'
+    [0x32, 0x49, 0x4a, 0x79, 0x00], // 'ã'
+    [0x30, 0x4a, 0x48, 0x7a, 0x00], // 'ä'
+    [0x32, 0x4d, 0x4d, 0x7a, 0x00], // 'å'
+    [0x28, 0x58, 0x78, 0x68, 0x50], // 'æ'
+    [0x00, 0x30, 0xc8, 0x48, 0x00], // 'ç'
+    [0x30, 0x69, 0x5a, 0x10, 0x00], // 'è'
+    [0x30, 0x6a, 0x59, 0x10, 0x00], // 'é'
+    [0x32, 0x69, 0x59, 0x12, 0x00], // 'ê'
+    [0x30, 0x6a, 0x58, 0x12, 0x00], // 'ë'
+    [0x00, 0x49, 0x7a, 0x40, 0x00], // 'ì'
+    [0x00, 0x48, 0x7a, 0x41, 0x00], // 'í'
+    [0x00, 0x4a, 0x79, 0x42, 0x00], // 'î'
+    [0x00, 0x4a, 0x78, 0x42, 0x00], // 'ï'
+    [0x25, 0x52, 0x55, 0x38, 0x00], // 'ð'
+    [0x7a, 0x09, 0x0a, 0x71, 0x00], // 'ñ'
+    [0x30, 0x49, 0x4a, 0x30, 0x00], // 'ò'
+    [0x30, 0x4a, 0x49, 0x30, 0x00], // 'ó'
+    [0x32, 0x49, 0x49, 0x32, 0x00], // 'ô'
+    [0x32, 0x49, 0x4a, 0x31, 0x00], // 'õ'
+    [0x32, 0x48, 0x48, 0x32, 0x00], // 'ö'
+    [0x00, 0x10, 0x54, 0x10, 0x00], // '÷'
+    [0x70, 0x68, 0x58, 0x38, 0x00], // 'ø'
+    [0x38, 0x41, 0x42, 0x78, 0x00], // 'ù'
+    [0x38, 0x42, 0x41, 0x78, 0x00], // 'ú'
+    [0x3a, 0x41, 0x41, 0x7a, 0x00], // 'û'
+    [0x3a, 0x40, 0x40, 0x7a, 0x00], // 'ü'
+    [0x58, 0xa2, 0xa1, 0x78, 0x00], // 'ý'
+    [0xfe, 0x28, 0x28, 0x10, 0x00], // 'þ'
+    [0x5a, 0xa0, 0xa0, 0x7a, 0x00], // 'ÿ'
+];
+
+/// Glyphs of the 5x8 font with an additional column of spacing on the right
+pub(super) static GLYPHS_6X8: [[u8; 6]; 191] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // ' '
+    [0x00, 0x00, 0x5e, 0x00, 0x00, 0x00], // '!'
+    [0x00, 0x0e, 0x00, 0x0e, 0x00, 0x00], // '"'
+    [0x14, 0x7f, 0x14, 0x7f, 0x14, 0x00], // '#'
+    [0x04, 0x2a, 0x7f, 0x2a, 0x10, 0x00], // '$'
+    [0x00, 0x16, 0x08, 0x34, 0x00, 0x00], // '%'
+    [0x36, 0x49, 0x36, 0x40, 0x00, 0x00], // '&'
+    [0x00, 0x00, 0x0e, 0x00, 0x00, 0x00], // '\''
+    [0x00, 0x3c, 0x42, 0x00, 0x00, 0x00], // '('
+    [0x00, 0x42, 0x3c, 0x00, 0x00, 0x00], // ')'
+    [0x54, 0x38, 0x38, 0x54, 0x00, 0x00], // '*'
+    [0x10, 0x10, 0x7c, 0x10, 0x10, 0x00], // '+'
+    [0x00, 0x80, 0x60, 0x20, 0x00, 0x00], // ','
+    [0x10, 0x10, 0x10, 0x10, 0x00, 0x00], // '-'
+    [0x00, 0x40, 0xe0, 0x40, 0x00, 0x00], // '.'
+    [0x60, 0x10, 0x08, 0x06, 0x00, 0x00], // '/'
+    [0x00, 0x3c, 0x42, 0x3c, 0x00, 0x00], // '0'
+    [0x00, 0x44, 0x7e, 0x40, 0x00, 0x00], // '1'
+    [0x64, 0x52, 0x52, 0x4c, 0x00, 0x00], // '2'
+    [0x22, 0x4a, 0x4e, 0x32, 0x00, 0x00], // '3'
+    [0x18, 0x14, 0x7e, 0x10, 0x00, 0x00], // '4'
+    [0x2e, 0x4a, 0x4a, 0x32, 0x00, 0x00], // '5'
+    [0x3c, 0x4a, 0x4a, 0x30, 0x00, 0x00], // '6'
+    [0x02, 0x62, 0x1a, 0x06, 0x00, 0x00], // '7'
+    [0x34, 0x4a, 0x4a, 0x34, 0x00, 0x00], // '8'
+    [0x0c, 0x52, 0x52, 0x3c, 0x00, 0x00], // '9'
+    [0x00, 0x6c, 0x6c, 0x00, 0x00, 0x00], // ':'
+    [0x00, 0x80, 0x6c, 0x2c, 0x00, 0x00], // ';'
+    [0x00, 0x18, 0x24, 0x42, 0x00, 0x00], // '<'
+    [0x28, 0x28, 0x28, 0x28, 0x00, 0x00], // '='
+    [0x00, 0x42, 0x24, 0x18, 0x00, 0x00], // '>'
+    [0x00, 0x04, 0x52, 0x0c, 0x00, 0x00], // '?'
+    [0x3c, 0x42, 0x99, 0xa5, 0x1e, 0x00], // '@'
+    [0x7c, 0x12, 0x12, 0x7c, 0x00, 0x00], // 'A'
+    [0x7e, 0x4a, 0x4a, 0x34, 0x00, 0x00], // 'B'
+    [0x3c, 0x42, 0x42, 0x24, 0x00, 0x00], // 'C'
+    [0x7e, 0x42, 0x42, 0x3c, 0x00, 0x00], // 'D'
+    [0x7e, 0x4a, 0x4a, 0x42, 0x00, 0x00], // 'E'
+    [0x7e, 0x0a, 0x0a, 0x02, 0x00, 0x00], // 'F'
+    [0x3c, 0x42, 0x52, 0x34, 0x00, 0x00], // 'G'
+    [0x7e, 0x08, 0x08, 0x7e, 0x00, 0x00], // 'H'
+    [0x00, 0x42, 0x7e, 0x42, 0x00, 0x00], // 'I'
+    [0x20, 0x42, 0x3e, 0x02, 0x00, 0x00], // 'J'
+    [0x7e, 0x08, 0x34, 0x42, 0x00, 0x00], // 'K'
+    [0x7e, 0x40, 0x40, 0x40, 0x00, 0x00], // 'L'
+    [0x7e, 0x0c, 0x0c, 0x7e, 0x00, 0x00], // 'M'
+    [0x7e, 0x0c, 0x38, 0x7e, 0x00, 0x00], // 'N'
+    [0x3c, 0x42, 0x42, 0x3c, 0x00, 0x00], // 'O'
+    [0x7e, 0x12, 0x12, 0x0c, 0x00, 0x00], // 'P'
+    [0x3c, 0x52, 0x62, 0xbc, 0x00, 0x00], // 'Q'
+    [0x7e, 0x12, 0x12, 0x6c, 0x00, 0x00], // 'R'
+    [0x24, 0x4a, 0x52, 0x24, 0x00, 0x00], // 'S'
+    [0x00, 0x02, 0x7e, 0x02, 0x00, 0x00], // 'T'
+    [0x3e, 0x40, 0x40, 0x3e, 0x00, 0x00], // 'U'
+    [0x1e, 0x60, 0x60, 0x1e, 0x00, 0x00], // 'V'
+    [0x7e, 0x30, 0x30, 0x7e, 0x00, 0x00], // 'W'
+    [0x66, 0x18, 0x18, 0x66, 0x00, 0x00], // 'X'
+    [0x06, 0x08, 0x70, 0x08, 0x06, 0x00], // 'Y'
+    [0x62, 0x52, 0x4a, 0x46, 0x00, 0x00], // 'Z'
+    [0x00, 0x7e, 0x42, 0x42, 0x00, 0x00], // '['
+    [0x06, 0x08, 0x10, 0x60, 0x00, 0x00], // '\\'
+    [0x00, 0x42, 0x42, 0x7e, 0x00, 0x00], // ']'
+    [0x00, 0x04, 0x02, 0x04, 0x00, 0x00], // '^'
+    [0x80, 0x80, 0x80, 0x80, 0x00, 0x00], // '_'
+    [0x00, 0x02, 0x04, 0x00, 0x00, 0x00], // '`'
+    [0x30, 0x48, 0x48, 0x78, 0x00, 0x00], // 'a'
+    [0x7e, 0x48, 0x48, 0x30, 0x00, 0x00], // 'b'
+    [0x00, 0x30, 0x48, 0x48, 0x00, 0x00], // 'c'
+    [0x30, 0x48, 0x48, 0x7e, 0x00, 0x00], // 'd'
+    [0x30, 0x68, 0x58, 0x10, 0x00, 0x00], // 'e'
+    [0x10, 0x7c, 0x12, 0x04, 0x00, 0x00], // 'f'
+    [0x10, 0xa8, 0xa8, 0x70, 0x00, 0x00], // 'g'
+    [0x7e, 0x08, 0x08, 0x70, 0x00, 0x00], // 'h'
+    [0x00, 0x48, 0x7a, 0x40, 0x00, 0x00], // 'i'
+    [0x00, 0x40, 0x80, 0x7a, 0x00, 0x00], // 'j'
+    [0x7e, 0x10, 0x10, 0x68, 0x00, 0x00], // 'k'
+    [0x00, 0x42, 0x7e, 0x40, 0x00, 0x00], // 'l'
+    [0x78, 0x08, 0x70, 0x08, 0x70, 0x00], // 'm'
+    [0x78, 0x08, 0x08, 0x70, 0x00, 0x00], // 'n'
+    [0x30, 0x48, 0x48, 0x30, 0x00, 0x00], // 'o'
+    [0xf8, 0x28, 0x28, 0x10, 0x00, 0x00], // 'p'
+    [0x10, 0x28, 0x28, 0xf8, 0x00, 0x00], // 'q'
+    [0x78, 0x10, 0x08, 0x10, 0x00, 0x00], // 'r'
+    [0x00, 0x50, 0x58, 0x28, 0x00, 0x00], // 's'
+    [0x08, 0x3e, 0x48, 0x20, 0x00, 0x00], // 't'
+    [0x38, 0x40, 0x40, 0x78, 0x00, 0x00], // 'u'
+    [0x00, 0x38, 0x40, 0x38, 0x00, 0x00], // 'v'
+    [0x38, 0x40, 0x30, 0x40, 0x38, 0x00], // 'w'
+    [0x48, 0x30, 0x30, 0x48, 0x00, 0x00], // 'x'
+    [0x58, 0xa0, 0xa0, 0x78, 0x00, 0x00], // 'y'
+    [0x48, 0x68, 0x58, 0x48, 0x00, 0x00], // 'z'
+    [0x08, 0x2a, 0x55, 0x41, 0x00, 0x00], // '{'
+    [0x00, 0x00, 0x7e, 0x00, 0x00, 0x00], // '|'
+    [0x41, 0x55, 0x2a, 0x08, 0x00, 0x00], // '}'
+    [0x04, 0x02, 0x04, 0x02, 0x00, 0x00], // '~'
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // '\u{a0}'
+    [0x00, 0x00, 0x7a, 0x00, 0x00, 0x00], // '¡'
+    [0x30, 0x48, 0xfc, 0x48, 0x00, 0x00], // '¢'
+    [0x48, 0x3c, 0x4a, 0x24, 0x00, 0x00], // '£'
+    [0x44, 0x38, 0x28, 0x38, 0x44, 0x00], // '¤'
+    [0x2a, 0x2c, 0x78, 0x2c, 0x2a, 0x00], // '¥'
+    [0x00, 0x00, 0x77, 0x00, 0x00, 0x00], // '¦'
+    [0x4e, 0x55, 0x55, 0x39, 0x00, 0x00], // '§'
+    [0x00, 0x02, 0x00, 0x02, 0x00, 0x00], // '¨'
+    [0x3c, 0x5a, 0x66, 0x42, 0x3c, 0x00], // '©'
+    [0x00, 0x12, 0x15, 0x17, 0x00, 0x00], // 'ª'
+    [0x10, 0x28, 0x10, 0x28, 0x00, 0x00], // '«'
+    [0x00, 0x10, 0x10, 0x70, 0x00, 0x00], // '¬'
+    [0x00, 0x10, 0x10, 0x10, 0x00, 0x00], // '\u{ad}'
+    [0x3c, 0x7e, 0x56, 0x6a, 0x3c, 0x00], // '®'
+    [0x00, 0x02, 0x02, 0x02, 0x00, 0x00], // '¯'
+    [0x00, 0x04, 0x0a, 0x04, 0x00, 0x00], // '°'
+    [0x00, 0x48, 0x5c, 0x48, 0x00, 0x00], // '±'
+    [0x00, 0x12, 0x19, 0x16, 0x00, 0x00], // '²'
+    [0x00, 0x15, 0x15, 0x0a, 0x00, 0x00], // '³'
+    [0x00, 0x04, 0x02, 0x00, 0x00, 0x00], // '´'
+    [0xf8, 0x40, 0x40, 0x38, 0x00, 0x00], // 'µ'
+    [0x0c, 0x1e, 0x7e, 0x02, 0x7e, 0x00], // '¶'
+    [0x00, 0x00, 0x10, 0x00, 0x00, 0x00], // '·'
+    [0x00, 0x80, 0x40, 0x00, 0x00, 0x00], // '¸'
+    [0x00, 0x12, 0x1f, 0x10, 0x00, 0x00], // '¹'
+    [0x00, 0x12, 0x15, 0x12, 0x00, 0x00], // 'º'
+    [0x28, 0x10, 0x28, 0x10, 0x00, 0x00], // '»'
+    [0x2f, 0x30, 0x78, 0x20, 0x00, 0x00], // '¼'
+    [0x0f, 0x48, 0x64, 0x58, 0x00, 0x00], // '½'
+    [0x35, 0x2a, 0x78, 0x20, 0x00, 0x00], // '¾'
+    [0x00, 0x30, 0x4a, 0x20, 0x00, 0x00], // '¿'
+    [0x78, 0x15, 0x16, 0x78, 0x00, 0x00], // 'À'
+    [0x78, 0x16, 0x15, 0x78, 0x00, 0x00], // 'Á'
+    [0x7a, 0x15, 0x15, 0x7a, 0x00, 0x00], // 'Â'
+    [0x7a, 0x15, 0x16, 0x79, 0x00, 0x00], // 'Ã'
+    [0x79, 0x14, 0x14, 0x79, 0x00, 0x00], // 'Ä'
+    [0x7a, 0x15, 0x15, 0x7a, 0x00, 0x00], // 'Å'
+    [0x7c, 0x12, 0x7e, 0x52, 0x00, 0x00], // 'Æ'
+    [0x3c, 0xc2, 0x42, 0x24, 0x00, 0x00], // 'Ç'
+    [0x7c, 0x55, 0x56, 0x44, 0x00, 0x00], // 'È'
+    [0x7c, 0x56, 0x55, 0x44, 0x00, 0x00], // 'É'
+    [0x7e, 0x55, 0x55, 0x46, 0x00, 0x00], // 'Ê'
+    [0x7d, 0x54, 0x54, 0x45, 0x00, 0x00], // 'Ë'
+    [0x00, 0x45, 0x7e, 0x44, 0x00, 0x00], // 'Ì'
+    [0x00, 0x44, 0x7e, 0x45, 0x00, 0x00], // 'Í'
+    [0x00, 0x46, 0x7d, 0x46, 0x00, 0x00], // 'Î'
+    [0x00, 0x45, 0x7c, 0x45, 0x00, 0x00], // 'Ï'
+    [0x08, 0x7e, 0x4a, 0x42, 0x3c, 0x00], // 'Ð'
+    [0x7e, 0x09, 0x12, 0x7d, 0x00, 0x00], // 'Ñ'
+    [0x38, 0x45, 0x46, 0x38, 0x00, 0x00], // 'Ò'
+    [0x38, 0x46, 0x45, 0x38, 0x00, 0x00], // 'Ó'
+    [0x3a, 0x45, 0x45, 0x3a, 0x00, 0x00], // 'Ô'
+    [0x3a, 0x45, 0x46, 0x39, 0x00, 0x00], // 'Õ'
+    [0x39, 0x44, 0x44, 0x39, 0x00, 0x00], // 'Ö'
+    [0x00, 0x50, 0x20, 0x50, 0x00, 0x00], // '×'
+    [0x7c, 0x72, 0x4e, 0x3e, 0x00, 0x00], // 'Ø'
+    [0x3c, 0x41, 0x42, 0x3c, 0x00, 0x00], // 'Ù'
+    [0x3c, 0x42, 0x41, 0x3c, 0x00, 0x00], // 'Ú'
+    [0x3e, 0x41, 0x41, 0x3e, 0x00, 0x00], // 'Û'
+    [0x3d, 0x40, 0x40, 0x3d, 0x00, 0x00], // 'Ü'
+    [0x04, 0x08, 0x72, 0x09, 0x04, 0x00], // 'Ý'
+    [0x7e, 0x24, 0x24, 0x18, 0x00, 0x00], // 'Þ'
+    [0x7c, 0x02, 0x5a, 0x24, 0x00, 0x00], // 'ß'
+    [0x30, 0x49, 0x4a, 0x78, 0x00, 0x00], // 'à'
+    [0x30, 0x4a, 0x49, 0x78, 0x00, 0x00], // 'á'
+    [0x30, 0x4a, 0x49, 0x7a, 0x00, 0x00], // 'â'
+    [0x32, 0x49, 0x4a, 0x79, 0x00, 0x00], // 'ã'
+    [0x30, 0x4a, 0x48, 0x7a, 0x00, 0x00], // 'ä'
+    [0x32, 0x4d, 0x4d, 0x7a, 0x00, 0x00], // 'å'
+    [0x28, 0x58, 0x78, 0x68, 0x50, 0x00], // 'æ'
+    [0x00, 0x30, 0xc8, 0x48, 0x00, 0x00], // 'ç'
+    [0x30, 0x69, 0x5a, 0x10, 0x00, 0x00], // 'è'
+    [0x30, 0x6a, 0x59, 0x10, 0x00, 0x00], // 'é'
+    [0x32, 0x69, 0x59, 0x12, 0x00, 0x00], // 'ê'
+    [0x30, 0x6a, 0x58, 0x12, 0x00, 0x00], // 'ë'
+    [0x00, 0x49, 0x7a, 0x40, 0x00, 0x00], // 'ì'
+    [0x00, 0x48, 0x7a, 0x41, 0x00, 0x00], // 'í'
+    [0x00, 0x4a, 0x79, 0x42, 0x00, 0x00], // 'î'
+    [0x00, 0x4a, 0x78, 0x42, 0x00, 0x00], // 'ï'
+    [0x25, 0x52, 0x55, 0x38, 0x00, 0x00], // 'ð'
+    [0x7a, 0x09, 0x0a, 0x71, 0x00, 0x00], // 'ñ'
+    [0x30, 0x49, 0x4a, 0x30, 0x00, 0x00], // 'ò'
+    [0x30, 0x4a, 0x49, 0x30, 0x00, 0x00], // 'ó'
+    [0x32, 0x49, 0x49, 0x32, 0x00, 0x00], // 'ô'
+    [0x32, 0x49, 0x4a, 0x31, 0x00, 0x00], // 'õ'
+    [0x32, 0x48, 0x48, 0x32, 0x00, 0x00], // 'ö'
+    [0x00, 0x10, 0x54, 0x10, 0x00, 0x00], // '÷'
+    [0x70, 0x68, 0x58, 0x38, 0x00, 0x00], // 'ø'
+    [0x38, 0x41, 0x42, 0x78, 0x00, 0x00], // 'ù'
+    [0x38, 0x42, 0x41, 0x78, 0x00, 0x00], // 'ú'
+    [0x3a, 0x41, 0x41, 0x7a, 0x00, 0x00], // 'û'
+    [0x3a, 0x40, 0x40, 0x7a, 0x00, 0x00], // 'ü'
+    [0x58, 0xa2, 0xa1, 0x78, 0x00, 0x00], // 'ý'
+    [0xfe, 0x28, 0x28, 0x10, 0x00, 0x00], // 'þ'
+    [0x5a, 0xa0, 0xa0, 0x7a, 0x00, 0x00], // 'ÿ'
+];
+
+/// Glyphs of the 8x13 font, with one blank row above and two below, one page per line
+#[rustfmt::skip]
+pub(super) static GLYPHS_8X16: [[u8; 16]; 191] = [
+    // ' '
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // '!'
+    [
+        0x00, 0x00, 0x00, 0xf8, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // '"'
+    [
+        0x00, 0x00, 0x38, 0x00, 0x00, 0x38, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // '#'
+    [
+        0x00, 0x40, 0xf0, 0x40, 0x40, 0xf0, 0x40, 0x00,
+        0x00, 0x01, 0x07, 0x01, 0x01, 0x07, 0x01, 0x00,
+    ],
+    // '$'
+    [
+        0x00, 0x60, 0x90, 0xf8, 0x90, 0x10, 0x00, 0x00,
+        0x00, 0x04, 0x04, 0x0f, 0x04, 0x03, 0x00, 0x00,
+    ],
+    // '%'
+    [
+        0x00, 0x10, 0x28, 0x10, 0xc0, 0x20, 0x18, 0x00,
+        0x00, 0x08, 0x06, 0x01, 0x04, 0x0a, 0x04, 0x00,
+    ],
+    // '&'
+    [
+        0x00, 0xc0, 0x20, 0x20, 0xc0, 0x00, 0x00, 0x00,
+        0x00, 0x06, 0x09, 0x09, 0x0a, 0x04, 0x0a, 0x00,
+    ],
+    // '\''
+    [
+        0x00, 0x00, 0x00, 0x38, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // '('
+    [
+        0x00, 0x00, 0x00, 0xc0, 0x30, 0x08, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x06, 0x08, 0x00, 0x00,
+    ],
+    // ')'
+    [
+        0x00, 0x00, 0x08, 0x30, 0xc0, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x08, 0x06, 0x01, 0x00, 0x00, 0x00,
+    ],
+    // '*'
+    [
+        0x00, 0x20, 0xa8, 0x70, 0x70, 0xa8, 0x20, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // '+'
+    [
+        0x00, 0x80, 0x80, 0xe0, 0x80, 0x80, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // ','
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x10, 0x0c, 0x0c, 0x04, 0x00, 0x00, 0x00,
+    ],
+    // '-'
+    [
+        0x00, 0x80, 0x80, 0x80, 0x80, 0x80, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // '.'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x08, 0x1c, 0x08, 0x00, 0x00, 0x00,
+    ],
+    // '/'
+    [
+        0x00, 0x00, 0x00, 0x80, 0x40, 0x20, 0x18, 0x00,
+        0x0c, 0x02, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // '0'
+    [
+        0x00, 0xe0, 0x10, 0x08, 0x08, 0x10, 0xe0, 0x00,
+        0x00, 0x03, 0x04, 0x08, 0x08, 0x04, 0x03, 0x00,
+    ],
+    // '1'
+    [
+        0x00, 0x20, 0x10, 0xf8, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x08, 0x08, 0x0f, 0x08, 0x08, 0x00, 0x00,
+    ],
+    // '2'
+    [
+        0x00, 0x30, 0x08, 0x08, 0x08, 0x88, 0x70, 0x00,
+        0x00, 0x0c, 0x0a, 0x09, 0x09, 0x08, 0x08, 0x00,
+    ],
+    // '3'
+    [
+        0x00, 0x08, 0x08, 0x88, 0xc8, 0xa8, 0x18, 0x00,
+        0x00, 0x04, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // '4'
+    [
+        0x00, 0x80, 0x40, 0x20, 0x10, 0xf8, 0x00, 0x00,
+        0x00, 0x03, 0x02, 0x02, 0x02, 0x0f, 0x02, 0x00,
+    ],
+    // '5'
+    [
+        0x00, 0xf8, 0x88, 0x48, 0x48, 0x48, 0x88, 0x00,
+        0x00, 0x04, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // '6'
+    [
+        0x00, 0xe0, 0x10, 0x88, 0x88, 0x88, 0x00, 0x00,
+        0x00, 0x07, 0x09, 0x08, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // '7'
+    [
+        0x00, 0x08, 0x08, 0x08, 0xc8, 0x28, 0x18, 0x00,
+        0x00, 0x00, 0x0c, 0x03, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // '8'
+    [
+        0x00, 0x70, 0x88, 0x88, 0x88, 0x88, 0x70, 0x00,
+        0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // '9'
+    [
+        0x00, 0x70, 0x88, 0x88, 0x88, 0x48, 0xf0, 0x00,
+        0x00, 0x00, 0x08, 0x08, 0x08, 0x04, 0x03, 0x00,
+    ],
+    // ':'
+    [
+        0x00, 0x00, 0x40, 0xe0, 0x40, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x08, 0x1c, 0x08, 0x00, 0x00, 0x00,
+    ],
+    // ';'
+    [
+        0x00, 0x00, 0x40, 0xe0, 0x40, 0x00, 0x00, 0x00,
+        0x00, 0x10, 0x0c, 0x0c, 0x04, 0x00, 0x00, 0x00,
+    ],
+    // '<'
+    [
+        0x00, 0x00, 0x80, 0x40, 0x20, 0x10, 0x08, 0x00,
+        0x00, 0x00, 0x00, 0x01, 0x02, 0x04, 0x08, 0x00,
+    ],
+    // '='
+    [
+        0x00, 0x40, 0x40, 0x40, 0x40, 0x40, 0x40, 0x00,
+        0x00, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02, 0x00,
+    ],
+    // '>'
+    [
+        0x00, 0x08, 0x10, 0x20, 0x40, 0x80, 0x00, 0x00,
+        0x00, 0x08, 0x04, 0x02, 0x01, 0x00, 0x00, 0x00,
+    ],
+    // '?'
+    [
+        0x00, 0x30, 0x08, 0x08, 0x08, 0x88, 0x70, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x0b, 0x00, 0x00, 0x00,
+    ],
+    // '@'
+    [
+        0x00, 0xf0, 0x08, 0x88, 0x48, 0x48, 0xf0, 0x00,
+        0x00, 0x07, 0x08, 0x09, 0x0a, 0x09, 0x03, 0x00,
+    ],
+    // 'A'
+    [
+        0x00, 0xe0, 0x10, 0x08, 0x08, 0x10, 0xe0, 0x00,
+        0x00, 0x0f, 0x01, 0x01, 0x01, 0x01, 0x0f, 0x00,
+    ],
+    // 'B'
+    [
+        0x00, 0xf8, 0x88, 0x88, 0x88, 0x50, 0x20, 0x00,
+        0x00, 0x0f, 0x08, 0x08, 0x08, 0x05, 0x02, 0x00,
+    ],
+    // 'C'
+    [
+        0x00, 0xf0, 0x08, 0x08, 0x08, 0x08, 0x10, 0x00,
+        0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x04, 0x00,
+    ],
+    // 'D'
+    [
+        0x00, 0xf8, 0x08, 0x08, 0x08, 0x10, 0xe0, 0x00,
+        0x00, 0x0f, 0x08, 0x08, 0x08, 0x04, 0x03, 0x00,
+    ],
+    // 'E'
+    [
+        0x00, 0xf8, 0x88, 0x88, 0x88, 0x08, 0x08, 0x00,
+        0x00, 0x0f, 0x08, 0x08, 0x08, 0x08, 0x08, 0x00,
+    ],
+    // 'F'
+    [
+        0x00, 0xf8, 0x88, 0x88, 0x88, 0x08, 0x08, 0x00,
+        0x00, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // 'G'
+    [
+        0x00, 0xf0, 0x08, 0x08, 0x08, 0x08, 0x10, 0x00,
+        0x00, 0x07, 0x08, 0x08, 0x09, 0x05, 0x0f, 0x00,
+    ],
+    // 'H'
+    [
+        0x00, 0xf8, 0x80, 0x80, 0x80, 0x80, 0xf8, 0x00,
+        0x00, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x0f, 0x00,
+    ],
+    // 'I'
+    [
+        0x00, 0x08, 0x08, 0xf8, 0x08, 0x08, 0x00, 0x00,
+        0x00, 0x08, 0x08, 0x0f, 0x08, 0x08, 0x00, 0x00,
+    ],
+    // 'J'
+    [
+        0x00, 0x00, 0x00, 0x08, 0x08, 0xf8, 0x08, 0x08,
+        0x00, 0x04, 0x08, 0x08, 0x08, 0x07, 0x00, 0x00,
+    ],
+    // 'K'
+    [
+        0x00, 0xf8, 0x80, 0x40, 0x20, 0x10, 0x08, 0x00,
+        0x00, 0x0f, 0x00, 0x01, 0x02, 0x04, 0x08, 0x00,
+    ],
+    // 'L'
+    [
+        0x00, 0xf8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x0f, 0x08, 0x08, 0x08, 0x08, 0x08, 0x00,
+    ],
+    // 'M'
+    [
+        0xf8, 0x20, 0x40, 0x80, 0x40, 0x20, 0xf8, 0x00,
+        0x0f, 0x00, 0x00, 0x01, 0x00, 0x00, 0x0f, 0x00,
+    ],
+    // 'N'
+    [
+        0x00, 0xf8, 0x20, 0x40, 0x80, 0x00, 0xf8, 0x00,
+        0x00, 0x0f, 0x00, 0x00, 0x00, 0x01, 0x0f, 0x00,
+    ],
+    // 'O'
+    [
+        0x00, 0xf0, 0x08, 0x08, 0x08, 0x08, 0xf0, 0x00,
+        0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // 'P'
+    [
+        0x00, 0xf8, 0x88, 0x88, 0x88, 0x88, 0x70, 0x00,
+        0x00, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // 'Q'
+    [
+        0x00, 0xf0, 0x08, 0x08, 0x08, 0x08, 0xf0, 0x00,
+        0x00, 0x07, 0x08, 0x0a, 0x0c, 0x08, 0x17, 0x00,
+    ],
+    // 'R'
+    [
+        0x00, 0xf8, 0x88, 0x88, 0x88, 0x88, 0x70, 0x00,
+        0x00, 0x0f, 0x00, 0x01, 0x02, 0x04, 0x08, 0x00,
+    ],
+    // 'S'
+    [
+        0x00, 0x70, 0x88, 0x88, 0x88, 0x88, 0x10, 0x00,
+        0x00, 0x04, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // 'T'
+    [
+        0x08, 0x08, 0x08, 0xf8, 0x08, 0x08, 0x08, 0x00,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // 'U'
+    [
+        0x00, 0xf8, 0x00, 0x00, 0x00, 0x00, 0xf8, 0x00,
+        0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // 'V'
+    [
+        0x18, 0xe0, 0x00, 0x00, 0x00, 0xe0, 0x18, 0x00,
+        0x00, 0x00, 0x07, 0x08, 0x07, 0x00, 0x00, 0x00,
+    ],
+    // 'W'
+    [
+        0xf8, 0x00, 0x00, 0x80, 0x00, 0x00, 0xf8, 0x00,
+        0x07, 0x08, 0x04, 0x03, 0x04, 0x08, 0x07, 0x00,
+    ],
+    // 'X'
+    [
+        0x18, 0x20, 0x40, 0x80, 0x40, 0x20, 0x18, 0x00,
+        0x0c, 0x02, 0x01, 0x00, 0x01, 0x02, 0x0c, 0x00,
+    ],
+    // 'Y'
+    [
+        0x18, 0x20, 0x40, 0x80, 0x40, 0x20, 0x18, 0x00,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // 'Z'
+    [
+        0x00, 0x08, 0x08, 0x88, 0x48, 0x28, 0x18, 0x00,
+        0x00, 0x0e, 0x09, 0x08, 0x08, 0x08, 0x08, 0x00,
+    ],
+    // '['
+    [
+        0x00, 0x00, 0xf8, 0x08, 0x08, 0x08, 0x00, 0x00,
+        0x00, 0x00, 0x0f, 0x08, 0x08, 0x08, 0x00, 0x00,
+    ],
+    // '\\'
+    [
+        0x18, 0x20, 0x40, 0x80, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x0c, 0x00,
+    ],
+    // ']'
+    [
+        0x00, 0x08, 0x08, 0x08, 0xf8, 0x00, 0x00, 0x00,
+        0x00, 0x08, 0x08, 0x08, 0x0f, 0x00, 0x00, 0x00,
+    ],
+    // '^'
+    [
+        0x00, 0x20, 0x10, 0x08, 0x10, 0x20, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // '_'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x00,
+    ],
+    // '`'
+    [
+        0x00, 0x00, 0x00, 0x04, 0x08, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // 'a'
+    [
+        0x00, 0x00, 0x40, 0x40, 0x40, 0x40, 0x80, 0x00,
+        0x00, 0x06, 0x09, 0x09, 0x09, 0x05, 0x0f, 0x00,
+    ],
+    // 'b'
+    [
+        0x00, 0xf8, 0x80, 0x40, 0x40, 0x40, 0x80, 0x00,
+        0x00, 0x0f, 0x04, 0x08, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // 'c'
+    [
+        0x00, 0x80, 0x40, 0x40, 0x40, 0x40, 0x80, 0x00,
+        0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x04, 0x00,
+    ],
+    // 'd'
+    [
+        0x00, 0x80, 0x40, 0x40, 0x40, 0x80, 0xf8, 0x00,
+        0x00, 0x07, 0x08, 0x08, 0x08, 0x04, 0x0f, 0x00,
+    ],
+    // 'e'
+    [
+        0x00, 0x80, 0x40, 0x40, 0x40, 0x40, 0x80, 0x00,
+        0x00, 0x07, 0x09, 0x09, 0x09, 0x09, 0x05, 0x00,
+    ],
+    // 'f'
+    [
+        0x00, 0x80, 0xf0, 0x88, 0x88, 0x88, 0x10, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // 'g'
+    [
+        0x00, 0x80, 0x40, 0x40, 0x40, 0x80, 0x40, 0x00,
+        0x00, 0x15, 0x2a, 0x2a, 0x2a, 0x29, 0x10, 0x00,
+    ],
+    // 'h'
+    [
+        0x00, 0xf8, 0x80, 0x40, 0x40, 0x40, 0x80, 0x00,
+        0x00, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x0f, 0x00,
+    ],
+    // 'i'
+    [
+        0x00, 0x00, 0x40, 0xd0, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x08, 0x08, 0x0f, 0x08, 0x08, 0x00, 0x00,
+    ],
+    // 'j'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x40, 0xd0, 0x00, 0x00,
+        0x00, 0x18, 0x20, 0x20, 0x20, 0x1f, 0x00, 0x00,
+    ],
+    // 'k'
+    [
+        0x00, 0xf8, 0x00, 0x00, 0x80, 0x40, 0x00, 0x00,
+        0x00, 0x0f, 0x01, 0x01, 0x02, 0x04, 0x08, 0x00,
+    ],
+    // 'l'
+    [
+        0x00, 0x00, 0x08, 0xf8, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x08, 0x08, 0x0f, 0x08, 0x08, 0x00, 0x00,
+    ],
+    // 'm'
+    [
+        0xc0, 0x40, 0x40, 0x80, 0x40, 0x40, 0x80, 0x00,
+        0x0f, 0x00, 0x00, 0x07, 0x00, 0x00, 0x0f, 0x00,
+    ],
+    // 'n'
+    [
+        0x00, 0xc0, 0x80, 0x40, 0x40, 0x40, 0x80, 0x00,
+        0x00, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x0f, 0x00,
+    ],
+    // 'o'
+    [
+        0x00, 0x80, 0x40, 0x40, 0x40, 0x40, 0x80, 0x00,
+        0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // 'p'
+    [
+        0x00, 0xc0, 0x80, 0x40, 0x40, 0x40, 0x80, 0x00,
+        0x00, 0x3f, 0x02, 0x04, 0x04, 0x04, 0x03, 0x00,
+    ],
+    // 'q'
+    [
+        0x00, 0x80, 0x40, 0x40, 0x40, 0x80, 0xc0, 0x00,
+        0x00, 0x03, 0x04, 0x04, 0x04, 0x02, 0x3f, 0x00,
+    ],
+    // 'r'
+    [
+        0x00, 0x40, 0x80, 0x40, 0x40, 0x40, 0x80, 0x00,
+        0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // 's'
+    [
+        0x00, 0x80, 0x40, 0x40, 0x40, 0x40, 0x80, 0x00,
+        0x00, 0x04, 0x09, 0x09, 0x0a, 0x0a, 0x04, 0x00,
+    ],
+    // 't'
+    [
+        0x00, 0x40, 0xf0, 0x40, 0x40, 0x40, 0x00, 0x00,
+        0x00, 0x00, 0x07, 0x08, 0x08, 0x08, 0x04, 0x00,
+    ],
+    // 'u'
+    [
+        0x00, 0xc0, 0x00, 0x00, 0x00, 0xc0, 0x00, 0x00,
+        0x00, 0x07, 0x08, 0x08, 0x08, 0x07, 0x08, 0x00,
+    ],
+    // 'v'
+    [
+        0x00, 0xc0, 0x00, 0x00, 0x00, 0xc0, 0x00, 0x00,
+        0x00, 0x01, 0x06, 0x08, 0x06, 0x01, 0x00, 0x00,
+    ],
+    // 'w'
+    [
+        0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0xc0, 0x00,
+        0x07, 0x08, 0x04, 0x03, 0x04, 0x08, 0x07, 0x00,
+    ],
+    // 'x'
+    [
+        0x00, 0x40, 0x80, 0x00, 0x00, 0x80, 0x40, 0x00,
+        0x00, 0x08, 0x04, 0x03, 0x03, 0x04, 0x08, 0x00,
+    ],
+    // 'y'
+    [
+        0x00, 0xc0, 0x00, 0x00, 0x00, 0x00, 0xc0, 0x00,
+        0x00, 0x13, 0x24, 0x24, 0x24, 0x22, 0x1f, 0x00,
+    ],
+    // 'z'
+    [
+        0x00, 0x40, 0x40, 0x40, 0x40, 0xc0, 0x40, 0x00,
+        0x00, 0x08, 0x0c, 0x0a, 0x09, 0x08, 0x08, 0x00,
+    ],
+    // '{'
+    [
+        0x00, 0x00, 0x80, 0xb0, 0x48, 0x08, 0x08, 0x00,
+        0x00, 0x00, 0x00, 0x06, 0x09, 0x08, 0x08, 0x00,
+    ],
+    // '|'
+    [
+        0x00, 0x00, 0x00, 0xf8, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // '}'
+    [
+        0x00, 0x08, 0x08, 0x48, 0xb0, 0x80, 0x00, 0x00,
+        0x00, 0x08, 0x08, 0x09, 0x06, 0x00, 0x00, 0x00,
+    ],
+    // '~'
+    [
+        0x00, 0x30, 0x08, 0x10, 0x20, 0x18, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // '\u{a0}'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // '¡'
+    [
+        0x00, 0x00, 0x00, 0xe8, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // '¢'
+    [
+        0x00, 0xe0, 0x10, 0xf8, 0x10, 0x20, 0x00, 0x00,
+        0x00, 0x01, 0x02, 0x07, 0x02, 0x01, 0x00, 0x00,
+    ],
+    // '£'
+    [
+        0x00, 0x40, 0xf0, 0x48, 0x08, 0x08, 0x10, 0x00,
+        0x08, 0x0c, 0x07, 0x08, 0x08, 0x08, 0x04, 0x00,
+    ],
+    // '¤'
+    [
+        0x00, 0x20, 0xc0, 0x40, 0x40, 0xc0, 0x20, 0x00,
+        0x00, 0x04, 0x03, 0x02, 0x02, 0x03, 0x04, 0x00,
+    ],
+    // '¥'
+    [
+        0x18, 0xa0, 0xc0, 0x80, 0xc0, 0xa0, 0x18, 0x00,
+        0x00, 0x02, 0x02, 0x0f, 0x02, 0x02, 0x00, 0x00,
+    ],
+    // '¦'
+    [
+        0x00, 0x00, 0x00, 0x78, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // '§'
+    [
+        0x00, 0x00, 0xd8, 0x24, 0x24, 0xc8, 0x00, 0x00,
+        0x00, 0x00, 0x04, 0x09, 0x09, 0x06, 0x00, 0x00,
+    ],
+    // '¨'
+    [
+        0x00, 0x00, 0x0c, 0x00, 0x00, 0x0c, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // '©'
+    [
+        0xf0, 0x08, 0xe4, 0x14, 0xa4, 0x08, 0xf0, 0x00,
+        0x01, 0x02, 0x04, 0x05, 0x04, 0x02, 0x01, 0x00,
+    ],
+    // 'ª'
+    [
+        0x00, 0x40, 0xa8, 0xa8, 0xa8, 0xf0, 0x00, 0x00,
+        0x00, 0x02, 0x02, 0x02, 0x02, 0x02, 0x00, 0x00,
+    ],
+    // '«'
+    [
+        0x80, 0x40, 0x20, 0x90, 0x40, 0x20, 0x10, 0x00,
+        0x00, 0x01, 0x02, 0x04, 0x01, 0x02, 0x04, 0x00,
+    ],
+    // '¬'
+    [
+        0x00, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0x00,
+    ],
+    // '\u{ad}'
+    [
+        0x00, 0x00, 0x80, 0x80, 0x80, 0x80, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // '®'
+    [
+        0xf0, 0x08, 0xe4, 0x94, 0x64, 0x08, 0xf0, 0x00,
+        0x01, 0x02, 0x05, 0x04, 0x05, 0x02, 0x01, 0x00,
+    ],
+    // '¯'
+    [
+        0x00, 0x08, 0x08, 0x08, 0x08, 0x08, 0x08, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // '°'
+    [
+        0x00, 0x00, 0x30, 0x48, 0x48, 0x30, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // '±'
+    [
+        0x00, 0x40, 0x40, 0xf0, 0x40, 0x40, 0x00, 0x00,
+        0x00, 0x04, 0x04, 0x05, 0x04, 0x04, 0x00, 0x00,
+    ],
+    // '²'
+    [
+        0x00, 0xc8, 0xa4, 0xa4, 0x98, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // '³'
+    [
+        0x00, 0x48, 0x84, 0x94, 0x68, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // '´'
+    [
+        0x00, 0x00, 0x00, 0x08, 0x04, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // 'µ'
+    [
+        0x00, 0xc0, 0x00, 0x00, 0x00, 0x00, 0xc0, 0x00,
+        0x00, 0x1f, 0x04, 0x08, 0x08, 0x04, 0x0f, 0x00,
+    ],
+    // '¶'
+    [
+        0x00, 0x70, 0xf8, 0xf8, 0x08, 0xf8, 0x08, 0x00,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x0f, 0x00, 0x00,
+    ],
+    // '·'
+    [
+        0x00, 0x00, 0x00, 0x80, 0x80, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // '¸'
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x20, 0x30, 0x00, 0x00, 0x00,
+    ],
+    // '¹'
+    [
+        0x00, 0x88, 0xfc, 0x80, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // 'º'
+    [
+        0x00, 0x30, 0x48, 0x48, 0x30, 0x00, 0x00, 0x00,
+        0x00, 0x01, 0x01, 0x01, 0x01, 0x00, 0x00, 0x00,
+    ],
+    // '»'
+    [
+        0x10, 0x20, 0x40, 0x90, 0x20, 0x40, 0x80, 0x00,
+        0x04, 0x02, 0x01, 0x04, 0x02, 0x01, 0x00, 0x00,
+    ],
+    // '¼'
+    [
+        0x88, 0xfc, 0x80, 0x00, 0x00, 0x80, 0xc0, 0x00,
+        0x00, 0x00, 0x00, 0x06, 0x05, 0x08, 0x0f, 0x00,
+    ],
+    // '½'
+    [
+        0x88, 0xfc, 0x80, 0x80, 0x40, 0x40, 0x80, 0x00,
+        0x00, 0x00, 0x00, 0x0c, 0x0a, 0x0a, 0x09, 0x00,
+    ],
+    // '¾'
+    [
+        0x48, 0x84, 0x94, 0x68, 0x00, 0x80, 0xc0, 0x00,
+        0x00, 0x00, 0x00, 0x06, 0x05, 0x08, 0x0f, 0x00,
+    ],
+    // '¿'
+    [
+        0x00, 0x00, 0x80, 0x68, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x06, 0x00,
+    ],
+    // 'À'
+    [
+        0x00, 0x80, 0x40, 0x24, 0x28, 0x40, 0x80, 0x00,
+        0x00, 0x0f, 0x02, 0x02, 0x02, 0x02, 0x0f, 0x00,
+    ],
+    // 'Á'
+    [
+        0x00, 0x80, 0x40, 0x28, 0x24, 0x40, 0x80, 0x00,
+        0x00, 0x0f, 0x02, 0x02, 0x02, 0x02, 0x0f, 0x00,
+    ],
+    // 'Â'
+    [
+        0x00, 0x80, 0x48, 0x24, 0x24, 0x48, 0x80, 0x00,
+        0x00, 0x0f, 0x02, 0x02, 0x02, 0x02, 0x0f, 0x00,
+    ],
+    // 'Ã'
+    [
+        0x00, 0x88, 0x44, 0x24, 0x28, 0x48, 0x84, 0x00,
+        0x00, 0x0f, 0x02, 0x02, 0x02, 0x02, 0x0f, 0x00,
+    ],
+    // 'Ä'
+    [
+        0x00, 0x80, 0x4c, 0x20, 0x20, 0x4c, 0x80, 0x00,
+        0x00, 0x0f, 0x02, 0x02, 0x02, 0x02, 0x0f, 0x00,
+    ],
+    // 'Å'
+    [
+        0x00, 0x80, 0x48, 0x34, 0x34, 0x48, 0x80, 0x00,
+        0x00, 0x0f, 0x02, 0x02, 0x02, 0x02, 0x0f, 0x00,
+    ],
+    // 'Æ'
+    [
+        0xf0, 0x08, 0x08, 0xf0, 0x88, 0x88, 0x08, 0x00,
+        0x0f, 0x01, 0x01, 0x0f, 0x08, 0x08, 0x08, 0x00,
+    ],
+    // 'Ç'
+    [
+        0x00, 0xf0, 0x08, 0x08, 0x08, 0x08, 0x10, 0x00,
+        0x00, 0x07, 0x08, 0x28, 0x18, 0x08, 0x04, 0x00,
+    ],
+    // 'È'
+    [
+        0x00, 0xe0, 0x20, 0x24, 0x28, 0x20, 0x20, 0x00,
+        0x00, 0x0f, 0x09, 0x09, 0x09, 0x08, 0x08, 0x00,
+    ],
+    // 'É'
+    [
+        0x00, 0xe0, 0x20, 0x28, 0x24, 0x20, 0x20, 0x00,
+        0x00, 0x0f, 0x09, 0x09, 0x09, 0x08, 0x08, 0x00,
+    ],
+    // 'Ê'
+    [
+        0x00, 0xe0, 0x28, 0x24, 0x24, 0x28, 0x20, 0x00,
+        0x00, 0x0f, 0x09, 0x09, 0x09, 0x08, 0x08, 0x00,
+    ],
+    // 'Ë'
+    [
+        0x00, 0xe0, 0x2c, 0x20, 0x20, 0x2c, 0x20, 0x00,
+        0x00, 0x0f, 0x09, 0x09, 0x09, 0x08, 0x08, 0x00,
+    ],
+    // 'Ì'
+    [
+        0x00, 0x20, 0x24, 0xe8, 0x20, 0x20, 0x00, 0x00,
+        0x00, 0x08, 0x08, 0x0f, 0x08, 0x08, 0x00, 0x00,
+    ],
+    // 'Í'
+    [
+        0x00, 0x20, 0x20, 0xe8, 0x24, 0x20, 0x00, 0x00,
+        0x00, 0x08, 0x08, 0x0f, 0x08, 0x08, 0x00, 0x00,
+    ],
+    // 'Î'
+    [
+        0x00, 0x20, 0x28, 0xe4, 0x24, 0x28, 0x00, 0x00,
+        0x00, 0x08, 0x08, 0x0f, 0x08, 0x08, 0x00, 0x00,
+    ],
+    // 'Ï'
+    [
+        0x00, 0x2c, 0x20, 0xe0, 0x20, 0x2c, 0x00, 0x00,
+        0x00, 0x08, 0x08, 0x0f, 0x08, 0x08, 0x00, 0x00,
+    ],
+    // 'Ð'
+    [
+        0x80, 0xf8, 0x88, 0x08, 0x08, 0x10, 0xe0, 0x00,
+        0x00, 0x0f, 0x08, 0x08, 0x08, 0x04, 0x03, 0x00,
+    ],
+    // 'Ñ'
+    [
+        0xe8, 0x44, 0x84, 0x08, 0x08, 0x04, 0xe0, 0x00,
+        0x0f, 0x00, 0x00, 0x01, 0x02, 0x04, 0x0f, 0x00,
+    ],
+    // 'Ò'
+    [
+        0xc0, 0x20, 0x24, 0x28, 0x20, 0x20, 0xc0, 0x00,
+        0x07, 0x08, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // 'Ó'
+    [
+        0xc0, 0x20, 0x20, 0x28, 0x24, 0x20, 0xc0, 0x00,
+        0x07, 0x08, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // 'Ô'
+    [
+        0xc0, 0x20, 0x28, 0x24, 0x24, 0x28, 0xc0, 0x00,
+        0x07, 0x08, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // 'Õ'
+    [
+        0xc8, 0x24, 0x24, 0x28, 0x28, 0x24, 0xc0, 0x00,
+        0x07, 0x08, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // 'Ö'
+    [
+        0xc0, 0x2c, 0x20, 0x20, 0x20, 0x2c, 0xc0, 0x00,
+        0x07, 0x08, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // '×'
+    [
+        0x00, 0x20, 0x40, 0x80, 0x80, 0x40, 0x20, 0x00,
+        0x00, 0x04, 0x02, 0x01, 0x01, 0x02, 0x04, 0x00,
+    ],
+    // 'Ø'
+    [
+        0x00, 0xf0, 0x08, 0x88, 0x68, 0x18, 0xf4, 0x00,
+        0x00, 0x17, 0x0c, 0x0b, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // 'Ù'
+    [
+        0x00, 0xe0, 0x04, 0x08, 0x00, 0x00, 0xe0, 0x00,
+        0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // 'Ú'
+    [
+        0x00, 0xe0, 0x00, 0x08, 0x04, 0x00, 0xe0, 0x00,
+        0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // 'Û'
+    [
+        0x00, 0xe0, 0x08, 0x04, 0x04, 0x08, 0xe0, 0x00,
+        0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // 'Ü'
+    [
+        0x00, 0xe0, 0x0c, 0x00, 0x00, 0x0c, 0xe0, 0x00,
+        0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // 'Ý'
+    [
+        0x00, 0x60, 0x80, 0x08, 0x84, 0x60, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // 'Þ'
+    [
+        0x00, 0xf8, 0x10, 0x10, 0x10, 0x10, 0xe0, 0x00,
+        0x00, 0x0f, 0x01, 0x01, 0x01, 0x01, 0x00, 0x00,
+    ],
+    // 'ß'
+    [
+        0x00, 0xf0, 0x08, 0x88, 0x48, 0x30, 0x00, 0x00,
+        0x00, 0x0f, 0x00, 0x08, 0x09, 0x09, 0x06, 0x00,
+    ],
+    // 'à'
+    [
+        0x00, 0x00, 0x40, 0x48, 0x50, 0x40, 0x80, 0x00,
+        0x00, 0x06, 0x09, 0x09, 0x09, 0x05, 0x0f, 0x00,
+    ],
+    // 'á'
+    [
+        0x00, 0x00, 0x40, 0x40, 0x50, 0x48, 0x80, 0x00,
+        0x00, 0x06, 0x09, 0x09, 0x09, 0x05, 0x0f, 0x00,
+    ],
+    // 'â'
+    [
+        0x00, 0x00, 0x50, 0x48, 0x48, 0x50, 0x80, 0x00,
+        0x00, 0x06, 0x09, 0x09, 0x09, 0x05, 0x0f, 0x00,
+    ],
+    // 'ã'
+    [
+        0x00, 0x10, 0x48, 0x48, 0x50, 0x50, 0x88, 0x00,
+        0x00, 0x06, 0x09, 0x09, 0x09, 0x05, 0x0f, 0x00,
+    ],
+    // 'ä'
+    [
+        0x00, 0x00, 0x58, 0x40, 0x40, 0x58, 0x80, 0x00,
+        0x00, 0x06, 0x09, 0x09, 0x09, 0x05, 0x0f, 0x00,
+    ],
+    // 'å'
+    [
+        0x00, 0x00, 0x48, 0x54, 0x54, 0x48, 0x80, 0x00,
+        0x00, 0x06, 0x09, 0x09, 0x09, 0x05, 0x0f, 0x00,
+    ],
+    // 'æ'
+    [
+        0x00, 0x40, 0x40, 0x80, 0x40, 0x40, 0x80, 0x00,
+        0x06, 0x09, 0x09, 0x07, 0x09, 0x09, 0x04, 0x00,
+    ],
+    // 'ç'
+    [
+        0x00, 0x80, 0x40, 0x40, 0x40, 0x40, 0x80, 0x00,
+        0x00, 0x07, 0x08, 0x28, 0x18, 0x08, 0x04, 0x00,
+    ],
+    // 'è'
+    [
+        0x00, 0x80, 0x40, 0x48, 0x50, 0x40, 0x80, 0x00,
+        0x00, 0x07, 0x09, 0x09, 0x09, 0x09, 0x05, 0x00,
+    ],
+    // 'é'
+    [
+        0x00, 0x80, 0x40, 0x50, 0x48, 0x40, 0x80, 0x00,
+        0x00, 0x07, 0x09, 0x09, 0x09, 0x09, 0x05, 0x00,
+    ],
+    // 'ê'
+    [
+        0x00, 0x80, 0x50, 0x48, 0x48, 0x50, 0x80, 0x00,
+        0x00, 0x07, 0x09, 0x09, 0x09, 0x09, 0x05, 0x00,
+    ],
+    // 'ë'
+    [
+        0x00, 0x80, 0x58, 0x40, 0x40, 0x58, 0x80, 0x00,
+        0x00, 0x07, 0x09, 0x09, 0x09, 0x09, 0x05, 0x00,
+    ],
+    // 'ì'
+    [
+        0x00, 0x00, 0x48, 0xd0, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x08, 0x08, 0x0f, 0x08, 0x08, 0x00, 0x00,
+    ],
+    // 'í'
+    [
+        0x00, 0x00, 0x50, 0xc8, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x08, 0x08, 0x0f, 0x08, 0x08, 0x00, 0x00,
+    ],
+    // 'î'
+    [
+        0x00, 0x10, 0x48, 0xc8, 0x10, 0x00, 0x00, 0x00,
+        0x00, 0x08, 0x08, 0x0f, 0x08, 0x08, 0x00, 0x00,
+    ],
+    // 'ï'
+    [
+        0x00, 0x18, 0x40, 0xc0, 0x18, 0x00, 0x00, 0x00,
+        0x00, 0x08, 0x08, 0x0f, 0x08, 0x08, 0x00, 0x00,
+    ],
+    // 'ð'
+    [
+        0x00, 0x80, 0x54, 0x48, 0x58, 0x64, 0x80, 0x00,
+        0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // 'ñ'
+    [
+        0x00, 0xd0, 0x88, 0x48, 0x50, 0x50, 0x88, 0x00,
+        0x00, 0x0f, 0x00, 0x00, 0x00, 0x00, 0x0f, 0x00,
+    ],
+    // 'ò'
+    [
+        0x00, 0x80, 0x48, 0x50, 0x40, 0x40, 0x80, 0x00,
+        0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // 'ó'
+    [
+        0x00, 0x80, 0x40, 0x50, 0x48, 0x40, 0x80, 0x00,
+        0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // 'ô'
+    [
+        0x00, 0x80, 0x50, 0x48, 0x48, 0x50, 0x80, 0x00,
+        0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // 'õ'
+    [
+        0x00, 0x90, 0x48, 0x48, 0x50, 0x50, 0x88, 0x00,
+        0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // 'ö'
+    [
+        0x00, 0x80, 0x58, 0x40, 0x40, 0x58, 0x80, 0x00,
+        0x00, 0x07, 0x08, 0x08, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // '÷'
+    [
+        0x00, 0x80, 0x80, 0xb0, 0x80, 0x80, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // 'ø'
+    [
+        0x00, 0x80, 0x40, 0x40, 0x40, 0xc0, 0xa0, 0x00,
+        0x00, 0x17, 0x0c, 0x0a, 0x09, 0x08, 0x07, 0x00,
+    ],
+    // 'ù'
+    [
+        0x00, 0xc0, 0x08, 0x10, 0x00, 0xc0, 0x00, 0x00,
+        0x00, 0x07, 0x08, 0x08, 0x08, 0x07, 0x08, 0x00,
+    ],
+    // 'ú'
+    [
+        0x00, 0xc0, 0x00, 0x10, 0x08, 0xc0, 0x00, 0x00,
+        0x00, 0x07, 0x08, 0x08, 0x08, 0x07, 0x08, 0x00,
+    ],
+    // 'û'
+    [
+        0x00, 0xc0, 0x10, 0x08, 0x08, 0xd0, 0x00, 0x00,
+        0x00, 0x07, 0x08, 0x08, 0x08, 0x07, 0x08, 0x00,
+    ],
+    // 'ü'
+    [
+        0x00, 0xc0, 0x18, 0x00, 0x18, 0xc0, 0x00, 0x00,
+        0x00, 0x07, 0x08, 0x08, 0x08, 0x07, 0x08, 0x00,
+    ],
+    // 'ý'
+    [
+        0x00, 0xc0, 0x00, 0x10, 0x08, 0x00, 0xc0, 0x00,
+        0x00, 0x13, 0x24, 0x24, 0x24, 0x22, 0x1f, 0x00,
+    ],
+    // 'þ'
+    [
+        0x00, 0xf0, 0x80, 0x40, 0x40, 0x40, 0x80, 0x00,
+        0x00, 0x3f, 0x04, 0x08, 0x08, 0x08, 0x07, 0x00,
+    ],
+    // 'ÿ'
+    [
+        0x00, 0xc0, 0x18, 0x00, 0x00, 0x18, 0xc0, 0x00,
+        0x00, 0x13, 0x24, 0x24, 0x24, 0x22, 0x1f, 0x00,
+    ],
+];
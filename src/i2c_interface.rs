@@ -1,6 +1,7 @@
 //! I2C interface factory
 
 use crate::{DataFormat, DisplayError, WriteOnlyDataCommand};
+use embedded_hal::i2c::Error;
 use embedded_hal_async as hal;
 
 /// Helper struct to create preconfigured I2C interfaces for the display.
@@ -9,6 +10,7 @@ pub struct I2CDisplayInterface(());
 
 impl I2CDisplayInterface {
     /// Create new builder with a default I2C address of 0x3C
+    #[allow(clippy::new_ret_no_self)]
     pub fn new<I>(i2c: I) -> I2CInterface<I>
     where
         I: hal::i2c::I2c,
@@ -76,7 +78,7 @@ where
                 self.i2c
                     .write(self.addr, &writebuf[..=slice.len()])
                     .await
-                    .map_err(|_| DisplayError::BusWriteError)?;
+                    .map_err(|e| DisplayError::from(e.kind()))?;
                 Ok(())
             }
             _ => Err(DisplayError::DataFormatNotImplemented),
@@ -105,7 +107,7 @@ where
                     self.i2c
                         .write(self.addr, &writebuf[0..=chunk_len])
                         .await
-                        .map_err(|_| DisplayError::BusWriteError)?;
+                        .map_err(|e| DisplayError::from(e.kind()))?;
                 }
 
                 Ok(())
@@ -124,18 +126,18 @@ where
 
                     if i == len {
                         self.i2c
-                            .write(self.addr, &writebuf[0..=len])
+                            .write(self.addr, &writebuf[..len])
                             .await
-                            .map_err(|_| DisplayError::BusWriteError)?;
+                            .map_err(|e| DisplayError::from(e.kind()))?;
                         i = 1;
                     }
                 }
 
                 if i > 1 {
                     self.i2c
-                        .write(self.addr, &writebuf[0..=i])
+                        .write(self.addr, &writebuf[..i])
                         .await
-                        .map_err(|_| DisplayError::BusWriteError)?;
+                        .map_err(|e| DisplayError::from(e.kind()))?;
                 }
 
                 Ok(())
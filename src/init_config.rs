@@ -0,0 +1,148 @@
+//! Display initialisation settings
+
+use crate::{brightness::Brightness, command::VcomhLevel};
+
+/// Settings used to initialise the display.
+///
+/// The defaults match the init sequence for the selected
+/// [`Controller`](crate::controller::Controller) and [`DisplaySize`](crate::size::DisplaySize).
+/// Individual settings can be overridden to tune a particular panel, and the result passed to
+/// [`DisplayConfig::init_with_config`](crate::mode::DisplayConfig::init_with_config).
+///
+/// ```rust
+/// use ssd1306_async::{command::VcomhLevel, prelude::*};
+///
+/// let config = InitConfig::new()
+///     .oscillator_freq(0xF)
+///     .precharge(2, 2)
+///     .vcomh(VcomhLevel::V083);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct InitConfig {
+    pub(crate) oscillator_freq: u8,
+    pub(crate) clock_div: u8,
+    pub(crate) charge_pump: bool,
+    pub(crate) vcomh: Option<u8>,
+    pub(crate) precharge_phase1: Option<u8>,
+    pub(crate) brightness: Brightness,
+    pub(crate) com_pin_cfg: Option<(bool, bool)>,
+    pub(crate) display_offset: u8,
+    pub(crate) start_line: u8,
+}
+
+impl Default for InitConfig {
+    fn default() -> Self {
+        InitConfig::new()
+    }
+}
+
+impl InitConfig {
+    /// Create the default init settings
+    pub const fn new() -> Self {
+        Self {
+            oscillator_freq: 0x8,
+            clock_div: 0x0,
+            charge_pump: true,
+            vcomh: None,
+            precharge_phase1: None,
+            brightness: Brightness::NORMAL,
+            com_pin_cfg: None,
+            display_offset: 0,
+            start_line: 0,
+        }
+    }
+
+    /// Set the oscillator frequency, from 0 to 15. Higher values increase the frame rate, which
+    /// can reduce visible flicker. Defaults to 8.
+    ///
+    /// Only the lower 4 bits are used, so larger values are truncated.
+    pub const fn oscillator_freq(self, freq: u8) -> Self {
+        Self {
+            oscillator_freq: freq & 0xF,
+            ..self
+        }
+    }
+
+    /// Set the display clock divide ratio minus one, from 0 to 15. Defaults to 0.
+    ///
+    /// Only the lower 4 bits are used, so larger values are truncated.
+    pub const fn clock_div(self, div: u8) -> Self {
+        Self {
+            clock_div: div & 0xF,
+            ..self
+        }
+    }
+
+    /// Enable or disable the internal charge pump, or the DC-DC converter on the SH1106.
+    ///
+    /// Disable it for panels with an external VCC supply. Defaults to enabled on controllers
+    /// which have one and is ignored on those which don't.
+    pub const fn charge_pump(self, enable: bool) -> Self {
+        Self {
+            charge_pump: enable,
+            ..self
+        }
+    }
+
+    /// Set the VCOMH deselect level. Defaults to a level suitable for the controller.
+    pub const fn vcomh(self, level: VcomhLevel) -> Self {
+        self.vcomh_raw((level as u8) << 4)
+    }
+
+    /// Set the raw VCOMH deselect level byte, for controllers whose levels are encoded differently
+    /// from [`VcomhLevel`].
+    pub const fn vcomh_raw(self, level: u8) -> Self {
+        Self {
+            vcomh: Some(level),
+            ..self
+        }
+    }
+
+    /// Set phase 1 and 2 of the precharge period, each from 1 to 15 DCLKs.
+    ///
+    /// Phase 2 is also set by [`brightness`](Self::brightness), whichever is called last wins.
+    /// Phase 1 is kept when the brightness is changed later on.
+    ///
+    /// Only the lower 4 bits of each phase are used, so larger values are truncated.
+    pub const fn precharge(self, phase1: u8, phase2: u8) -> Self {
+        Self {
+            precharge_phase1: Some(phase1 & 0xF),
+            brightness: Brightness::custom(phase2 & 0xF, self.brightness.contrast),
+            ..self
+        }
+    }
+
+    /// Set the brightness the display is initialised with. Defaults to [`Brightness::NORMAL`].
+    pub const fn brightness(self, brightness: Brightness) -> Self {
+        Self { brightness, ..self }
+    }
+
+    /// Override the COM pins hardware configuration of the display size, see
+    /// [`Command::ComPinConfig`](crate::command::Command::ComPinConfig).
+    pub const fn com_pin_cfg(self, alternative: bool, left_right_remap: bool) -> Self {
+        Self {
+            com_pin_cfg: Some((alternative, left_right_remap)),
+            ..self
+        }
+    }
+
+    /// Set the vertical display offset, from 0 to 63. Defaults to 0.
+    ///
+    /// Only the lower 6 bits are used, so larger values are truncated.
+    pub const fn display_offset(self, offset: u8) -> Self {
+        Self {
+            display_offset: offset & 0x3F,
+            ..self
+        }
+    }
+
+    /// Set the display start line, from 0 to 63. Defaults to 0.
+    ///
+    /// Only the lower 6 bits are used, so larger values are truncated.
+    pub const fn start_line(self, line: u8) -> Self {
+        Self {
+            start_line: line & 0x3F,
+            ..self
+        }
+    }
+}
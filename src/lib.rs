@@ -17,6 +17,16 @@
 //! Examples can be found in [the examples/
 //! folder](https://github.com/kalkyl/ssd1306-async/blob/main/examples)
 //!
+//! # Blocking operation
+//!
+//! With the `blocking` feature enabled, the driver can be used with blocking I2C and SPI
+//! peripherals, without an async executor. See the [`blocking`] module.
+//!
+//! # Testing
+//!
+//! With the `emulator` feature enabled, [`emulator::EmulatedDisplay`] can be used in place of a
+//! real I2C or SPI interface to check what would be shown on the panel on the host.
+//!
 //! [featureset]: https://github.com/jamwaffles/embedded-graphics#features
 //! [`BufferedGraphicsMode`]: crate::mode::BufferedGraphicsMode
 //! [`TerminalMode`]: crate::mode::TerminalMode
@@ -37,10 +47,20 @@
 // #![deny(rustdoc::broken_intra_doc_links)]
 #![allow(async_fn_in_trait)]
 
+#[cfg(feature = "emulator")]
+extern crate std;
+
+#[cfg(feature = "blocking")]
+pub mod blocking;
 mod brightness;
 pub mod command;
+pub mod controller;
+#[cfg(feature = "emulator")]
+pub mod emulator;
 mod error;
+pub mod font;
 pub mod i2c_interface;
+mod init_config;
 pub mod mode;
 pub mod prelude;
 pub mod rotation;
@@ -52,10 +72,15 @@ use crate::mode::BasicMode;
 pub use crate::spi_interface::SPIInterface;
 use crate::DataFormat::U8;
 use brightness::Brightness;
-use command::{AddrMode, Command, VcomhLevel};
-use embedded_hal::{delay::DelayNs, digital::OutputPin};
-use error::Error;
-use mode::{BufferedGraphicsMode, TerminalMode};
+use command::{AddrMode, Command, FadeMode, HScrollDir, NFrames, Page, VHScrollDir};
+use controller::Controller;
+use core::ops::RangeInclusive;
+use embedded_hal::{digital::OutputPin, i2c, spi};
+use embedded_hal_async::delay::DelayNs;
+pub use error::Error;
+use font::TerminalFont;
+use init_config::InitConfig;
+use mode::{BufferedGraphicsMode, DisplayConfig, TerminalMode};
 use rotation::DisplayRotation;
 use size::DisplaySize;
 
@@ -69,6 +94,17 @@ pub struct Ssd1306<DI, SIZE, MODE> {
     size: SIZE,
     addr_mode: AddrMode,
     rotation: DisplayRotation,
+    controller: Controller,
+    config: InitConfig,
+    draw_area_start: (u8, u8),
+    display_on: bool,
+    inverted: bool,
+    all_on: bool,
+    mirrored: bool,
+    scrolling: bool,
+    /// Number of rows moved by diagonal scrolling
+    scroll_rows: u8,
+    brightness: Brightness,
 }
 
 impl<DI, SIZE> Ssd1306<DI, SIZE, BasicMode>
@@ -80,14 +116,32 @@ where
     ///
     /// Use the `into_*_mode` methods to enable more functionality.
     pub fn new(interface: DI, size: SIZE, rotation: DisplayRotation) -> Self {
+        let scroll_rows = size.height();
         Self {
             interface,
             size,
             addr_mode: AddrMode::Page,
             mode: BasicMode,
             rotation,
+            controller: Controller::Ssd1306,
+            config: InitConfig::new(),
+            draw_area_start: (0, 0),
+            display_on: false,
+            inverted: false,
+            all_on: false,
+            mirrored: false,
+            scrolling: false,
+            scroll_rows,
+            brightness: Brightness::default(),
         }
     }
+
+    /// Select the display controller to target instead of the default SSD1306.
+    ///
+    /// This must be set before the display is initialised.
+    pub fn with_controller(self, controller: Controller) -> Self {
+        Self { controller, ..self }
+    }
 }
 
 impl<DI, SIZE, MODE> Ssd1306<DI, SIZE, MODE>
@@ -97,13 +151,35 @@ where
 {
     /// Convert the display into another interface mode.
     fn into_mode<MODE2>(self, mode: MODE2) -> Ssd1306<DI, SIZE, MODE2> {
-        Ssd1306 {
+        self.replace_mode(mode).0
+    }
+
+    /// Convert the display into another interface mode, returning the previous mode.
+    fn replace_mode<MODE2>(self, mode: MODE2) -> (Ssd1306<DI, SIZE, MODE2>, MODE) {
+        let display = Ssd1306 {
             mode,
             addr_mode: self.addr_mode,
             interface: self.interface,
             size: self.size,
             rotation: self.rotation,
-        }
+            controller: self.controller,
+            config: self.config,
+            draw_area_start: self.draw_area_start,
+            display_on: self.display_on,
+            inverted: self.inverted,
+            all_on: self.all_on,
+            mirrored: self.mirrored,
+            scrolling: self.scrolling,
+            scroll_rows: self.scroll_rows,
+            brightness: self.brightness,
+        };
+
+        (display, self.mode)
+    }
+
+    /// Consume the display driver and return the underlying display interface.
+    pub fn release(self) -> DI {
+        self.interface
     }
 
     /// Convert the display into a buffered graphics mode, supporting
@@ -114,6 +190,23 @@ where
         self.into_mode(BufferedGraphicsMode::new())
     }
 
+    /// Convert the display into a buffered graphics mode which keeps the pixels in `buffer`
+    /// instead of `SIZE::Buffer`.
+    ///
+    /// The buffer must hold at least `width * height / 8` bytes, otherwise
+    /// [`init`](mode::DisplayConfig::init) and [`flush`](Self::flush) return
+    /// [`DisplayError::OutOfBoundsError`]. This allows sizing the buffer at runtime along with
+    /// [`RuntimeSize`](size::RuntimeSize).
+    pub fn into_buffered_graphics_mode_with_buffer<B>(
+        self,
+        buffer: B,
+    ) -> Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE, B>>
+    where
+        B: AsMut<[u8]>,
+    {
+        self.into_mode(BufferedGraphicsMode::with_buffer(buffer))
+    }
+
     /// Convert the display into a text-only, terminal-like mode.
     ///
     /// See [TerminalMode] for more information.
@@ -121,39 +214,76 @@ where
         self.into_mode(TerminalMode::new())
     }
 
+    /// Convert the display into a text-only, terminal-like mode using a custom font.
+    ///
+    /// See [TerminalMode] and [`font`] for more information.
+    pub fn into_terminal_mode_with_font<F>(self, font: F) -> Ssd1306<DI, SIZE, TerminalMode<F>>
+    where
+        F: TerminalFont,
+    {
+        self.into_mode(TerminalMode::with_font(font))
+    }
+
     /// Initialise the display in one of the available addressing modes.
+    ///
+    /// The display is configured using the settings last passed to
+    /// [`DisplayConfig::init_with_config`](mode::DisplayConfig::init_with_config), or the
+    /// defaults if there are none.
+    ///
+    /// Controllers which don't support the horizontal and vertical addressing modes are always
+    /// initialised in page addressing mode.
     pub async fn init_with_addr_mode(&mut self, mode: AddrMode) -> Result<(), DisplayError> {
         let rotation = self.rotation;
+        let config = self.config;
+        let mode = if self.controller.supports_addr_modes() {
+            mode
+        } else {
+            AddrMode::Page
+        };
 
-        Command::DisplayOn(false).send(&mut self.interface).await?;
-        Command::DisplayClockDiv(0x8, 0x0)
+        self.set_display_on(false).await?;
+        Command::DisplayClockDiv(config.oscillator_freq, config.clock_div)
             .send(&mut self.interface)
             .await?;
-        Command::Multiplex(SIZE::HEIGHT - 1)
+        Command::Multiplex(self.size.height() - 1)
             .send(&mut self.interface)
             .await?;
-        Command::DisplayOffset(0).send(&mut self.interface).await?;
-        Command::StartLine(0).send(&mut self.interface).await?;
-        // TODO: Ability to turn charge pump on/off
-        Command::ChargePump(true).send(&mut self.interface).await?;
-        Command::AddressMode(mode).send(&mut self.interface).await?;
-
-        self.size.com_pin_cfg().send(&mut self.interface).await?;
-        if let Some(cmd) = self.size.int_iref() {
-            cmd.send(&mut self.interface).await?
-        }
-        self.set_rotation(rotation).await?;
-
-        self.set_brightness(Brightness::default()).await?;
-        Command::VcomhDeselect(VcomhLevel::Auto)
+        Command::DisplayOffset(config.display_offset)
             .send(&mut self.interface)
             .await?;
-        Command::AllOn(false).send(&mut self.interface).await?;
-        Command::Invert(false).send(&mut self.interface).await?;
-        Command::EnableScroll(false)
+        // Show the display RAM rows of the panel, starting at its vertical offset
+        Command::StartLine(config.start_line + self.size.offset_y())
             .send(&mut self.interface)
             .await?;
-        Command::DisplayOn(true).send(&mut self.interface).await?;
+        if let Some(cmd) = self.controller.power_supply(config.charge_pump) {
+            cmd.send(&mut self.interface).await?;
+        }
+        if self.controller.supports_addr_modes() {
+            Command::AddressMode(mode).send(&mut self.interface).await?;
+        }
+
+        let com_pin_cfg = match config.com_pin_cfg {
+            Some((alternative, lr_remap)) => Command::ComPinConfig(alternative, lr_remap),
+            None => self.controller.com_pin_cfg(self.size.com_pin_cfg()),
+        };
+        com_pin_cfg.send(&mut self.interface).await?;
+        if let (true, Some(cmd)) = (self.controller.supports_int_iref(), self.size.int_iref()) {
+            cmd.send(&mut self.interface).await?;
+        }
+        self.set_rotation(rotation).await?;
+
+        self.set_brightness(config.brightness).await?;
+        let vcomh = match config.vcomh {
+            Some(level) => Command::VcomhDeselectRaw(level),
+            None => self.controller.vcomh_deselect(),
+        };
+        vcomh.send(&mut self.interface).await?;
+        self.set_all_on(false).await?;
+        self.set_invert(false).await?;
+        if self.controller.supports_scrolling() {
+            self.disable_scroll().await?;
+        }
+        self.set_display_on(true).await?;
 
         self.addr_mode = mode;
 
@@ -161,7 +291,13 @@ where
     }
 
     /// Change the addressing mode
+    ///
+    /// This is a noop for controllers which only support page addressing.
     pub async fn set_addr_mode(&mut self, mode: AddrMode) -> Result<(), DisplayError> {
+        if !self.controller.supports_addr_modes() {
+            return Ok(());
+        }
+
         Command::AddressMode(mode).send(&mut self.interface).await?;
         self.addr_mode = mode;
         Ok(())
@@ -179,8 +315,10 @@ where
         upper_left: (u8, u8),
         lower_right: (u8, u8),
     ) -> Result<(), DisplayError> {
+        let page_origin = self.page_origin();
         Self::flush_buffer_chunks(
             &mut self.interface,
+            page_origin,
             buffer,
             disp_width,
             upper_left,
@@ -191,13 +329,17 @@ where
 
     /// Send a raw buffer to the display.
     pub async fn draw(&mut self, buffer: &[u8]) -> Result<(), DisplayError> {
-        self.interface.send_data(U8(&buffer)).await
+        self.interface.send_data(U8(buffer)).await
     }
 
     pub fn dimensions(&self) -> (u8, u8) {
         match self.rotation {
-            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (SIZE::WIDTH, SIZE::HEIGHT),
-            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (SIZE::HEIGHT, SIZE::WIDTH),
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
+                (self.size.width(), self.size.height())
+            }
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
+                (self.size.height(), self.size.width())
+            }
         }
     }
 
@@ -206,9 +348,59 @@ where
         self.rotation
     }
 
+    /// Get the display controller.
+    pub fn controller(&self) -> Controller {
+        self.controller
+    }
+
+    /// Whether the display is switched on.
+    pub fn is_on(&self) -> bool {
+        self.display_on
+    }
+
+    /// Whether the display is inverted.
+    pub fn is_inverted(&self) -> bool {
+        self.inverted
+    }
+
+    /// Whether all pixels are forced on.
+    pub fn is_all_on(&self) -> bool {
+        self.all_on
+    }
+
+    /// Whether the display is mirrored.
+    pub fn is_mirrored(&self) -> bool {
+        self.mirrored
+    }
+
+    /// Whether hardware scrolling is active.
+    pub fn is_scrolling(&self) -> bool {
+        self.scrolling
+    }
+
+    /// Get the display brightness.
+    pub fn brightness(&self) -> Brightness {
+        self.brightness
+    }
+
+    /// Column in the GDDRAM where the first column of the panel is displayed.
+    pub(crate) fn column_offset(&self) -> u8 {
+        let offset = match self.rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate270 => self.size.offset_x(),
+            DisplayRotation::Rotate180 | DisplayRotation::Rotate90 => {
+                // If segment remapping is flipped, we need to calculate
+                // the offset from the other edge of the display.
+                SIZE::DRIVER_COLS - self.size.width() - self.size.offset_x()
+            }
+        };
+
+        offset + self.controller.column_offset()
+    }
+
     /// Set the display rotation.
     pub async fn set_rotation(&mut self, rotation: DisplayRotation) -> Result<(), DisplayError> {
         self.rotation = rotation;
+        self.mirrored = false;
 
         match rotation {
             DisplayRotation::Rotate0 => {
@@ -288,6 +480,7 @@ where
         } else {
             self.set_rotation(self.rotation).await?;
         }
+        self.mirrored = mirror;
         Ok(())
     }
 
@@ -299,39 +492,255 @@ where
             "Precharge value must be between 1 and 15"
         );
 
-        Command::PreChargePeriod(1, brightness.precharge)
+        let phase1 = self
+            .config
+            .precharge_phase1
+            .unwrap_or(self.controller.precharge_phase1());
+
+        Command::PreChargePeriod(phase1, brightness.precharge)
             .send(&mut self.interface)
             .await?;
         Command::Contrast(brightness.contrast)
             .send(&mut self.interface)
-            .await
+            .await?;
+        self.brightness = brightness;
+        Ok(())
     }
 
     /// Turn the display on or off. The display can be drawn to and retains all
     /// of its memory even while off.
     pub async fn set_display_on(&mut self, on: bool) -> Result<(), DisplayError> {
-        Command::DisplayOn(on).send(&mut self.interface).await
+        Command::DisplayOn(on).send(&mut self.interface).await?;
+        self.display_on = on;
+        Ok(())
+    }
+
+    /// Invert the display, showing pixels which are off as lit and vice versa.
+    pub async fn set_invert(&mut self, invert: bool) -> Result<(), DisplayError> {
+        Command::Invert(invert).send(&mut self.interface).await?;
+        self.inverted = invert;
+        Ok(())
+    }
+
+    /// Light all pixels regardless of the display RAM contents, e.g. to test the panel. The RAM
+    /// contents are shown again once disabled.
+    pub async fn set_all_on(&mut self, all_on: bool) -> Result<(), DisplayError> {
+        Command::AllOn(all_on).send(&mut self.interface).await?;
+        self.all_on = all_on;
+        Ok(())
+    }
+
+    /// Fade out or blink the display, changing the brightness every 8 * (`interval` + 1) frames.
+    ///
+    /// `interval` must be between 0 and 15. Use [`FadeMode::Disabled`] to return to normal
+    /// operation.
+    pub async fn set_fade(&mut self, mode: FadeMode, interval: u8) -> Result<(), DisplayError> {
+        if !self.controller.supports_fade_and_zoom() {
+            return Err(DisplayError::NotSupported);
+        }
+
+        if interval > 15 {
+            return Err(DisplayError::OutOfBoundsError);
+        }
+
+        Command::Fade(mode, interval)
+            .send(&mut self.interface)
+            .await
+    }
+
+    /// Double the vertical size of the displayed content, showing only its upper half.
+    ///
+    /// This only works with the alternative COM pin configuration, as used by 128x64 displays.
+    pub async fn set_zoom(&mut self, zoom: bool) -> Result<(), DisplayError> {
+        if !self.controller.supports_fade_and_zoom() {
+            return Err(DisplayError::NotSupported);
+        }
+
+        Command::Zoom(zoom).send(&mut self.interface).await
+    }
+
+    /// Start continuously scrolling the GDDRAM pages in `pages` horizontally, one column every
+    /// `rate` frames.
+    ///
+    /// Pages are counted from the first page of the display area, top to bottom for
+    /// [`DisplayRotation::Rotate0`]. Returns [`DisplayError::OutOfBoundsError`] if `pages` is
+    /// empty or extends past the bottom of the display, and [`DisplayError::NotSupported`] for
+    /// controllers without hardware scrolling.
+    ///
+    /// The display RAM must not be written to while scrolling. Call `stop_scroll` when done.
+    pub async fn start_horizontal_scroll(
+        &mut self,
+        direction: HScrollDir,
+        pages: RangeInclusive<u8>,
+        rate: NFrames,
+    ) -> Result<(), DisplayError> {
+        let (start, end) = self.scroll_pages(pages)?;
+
+        self.disable_scroll().await?;
+        Command::HScrollSetup(direction, start, end, rate)
+            .send(&mut self.interface)
+            .await?;
+        self.enable_scroll().await
+    }
+
+    /// Start continuously scrolling the GDDRAM pages in `pages` horizontally, one column every
+    /// `rate` frames, while the vertical scroll area moves up by `vertical_offset` rows at the
+    /// same time.
+    ///
+    /// `vertical_offset` must be less than the number of rows that scroll vertically, which is the
+    /// display height unless limited using
+    /// [`set_vertical_scroll_area`](Self::set_vertical_scroll_area). See
+    /// [`start_horizontal_scroll`](Self::start_horizontal_scroll) for the other arguments.
+    pub async fn start_diagonal_scroll(
+        &mut self,
+        direction: VHScrollDir,
+        pages: RangeInclusive<u8>,
+        rate: NFrames,
+        vertical_offset: u8,
+    ) -> Result<(), DisplayError> {
+        let (start, end) = self.scroll_pages(pages)?;
+
+        if vertical_offset >= self.scroll_rows {
+            return Err(DisplayError::OutOfBoundsError);
+        }
+
+        self.disable_scroll().await?;
+        Command::VHScrollSetup(direction, start, end, rate, vertical_offset)
+            .send(&mut self.interface)
+            .await?;
+        self.enable_scroll().await
+    }
+
+    /// Limit the rows moved by [`start_diagonal_scroll`](Self::start_diagonal_scroll) to the
+    /// `scroll_rows` rows below the first `fixed_rows` rows of the display.
+    ///
+    /// Returns [`DisplayError::OutOfBoundsError`] if the area extends past the bottom of the
+    /// display.
+    pub async fn set_vertical_scroll_area(
+        &mut self,
+        fixed_rows: u8,
+        scroll_rows: u8,
+    ) -> Result<(), DisplayError> {
+        if !self.controller.supports_scrolling() {
+            return Err(DisplayError::NotSupported);
+        }
+
+        if fixed_rows as u16 + scroll_rows as u16 > self.size.height() as u16 {
+            return Err(DisplayError::OutOfBoundsError);
+        }
+
+        // The fixed rows are counted from the top of the display RAM, not of the panel
+        Command::VScrollArea(fixed_rows + self.size.offset_y(), scroll_rows)
+            .send(&mut self.interface)
+            .await?;
+        self.scroll_rows = scroll_rows;
+        Ok(())
+    }
+
+    /// Start hardware scrolling after it has been set up
+    async fn enable_scroll(&mut self) -> Result<(), DisplayError> {
+        Command::EnableScroll(true)
+            .send(&mut self.interface)
+            .await?;
+        self.scrolling = true;
+        Ok(())
+    }
+
+    /// Stop hardware scrolling, without restoring the display RAM
+    pub(crate) async fn disable_scroll(&mut self) -> Result<(), DisplayError> {
+        if !self.controller.supports_scrolling() {
+            return Err(DisplayError::NotSupported);
+        }
+
+        Command::EnableScroll(false)
+            .send(&mut self.interface)
+            .await?;
+        self.scrolling = false;
+        Ok(())
+    }
+
+    /// Validate a range of scrolled pages and convert it to GDDRAM pages
+    fn scroll_pages(&self, pages: RangeInclusive<u8>) -> Result<(Page, Page), DisplayError> {
+        if !self.controller.supports_scrolling() {
+            return Err(DisplayError::NotSupported);
+        }
+
+        let (start, end) = pages.into_inner();
+        if start > end || end >= self.size.height().div_ceil(8) {
+            return Err(DisplayError::OutOfBoundsError);
+        }
+
+        let first_page = self.size.offset_y() / 8;
+        Ok((
+            ((first_page + start) * 8).into(),
+            ((first_page + end) * 8).into(),
+        ))
     }
 
     /// Set the position in the framebuffer of the display limiting where any sent data should be
     /// drawn. This method can be used for changing the affected area on the screen as well
     /// as (re-)setting the start point of the next `draw` call.
+    ///
+    /// In page addressing mode only the start point is set, so a `draw` call will not wrap to the
+    /// next page. [`bounded_draw`](Self::bounded_draw) takes care of this by positioning every
+    /// page separately.
     pub async fn set_draw_area(
         &mut self,
         start: (u8, u8),
         end: (u8, u8),
     ) -> Result<(), DisplayError> {
+        self.draw_area_start = start;
+
+        if self.addr_mode == AddrMode::Page {
+            self.set_row(start.1).await?;
+            return self.set_column(start.0).await;
+        }
+
         Command::ColumnAddress(start.0, end.0.saturating_sub(1))
             .send(&mut self.interface)
             .await?;
+        Command::PageAddress(start.1.into(), (end.1.saturating_sub(1)).into())
+            .send(&mut self.interface)
+            .await
+    }
 
-        if self.addr_mode != AddrMode::Page {
-            Command::PageAddress(start.1.into(), (end.1.saturating_sub(1)).into())
-                .send(&mut self.interface)
-                .await?;
-        }
+    /// Fill the visible area of the framebuffer of the display with `byte`.
+    pub(crate) async fn fill_display(&mut self, byte: u8) -> Result<(), DisplayError> {
+        let offset_x = self.column_offset();
+        let offset_y = self.size.offset_y();
+        self.fill_area(
+            (offset_x, offset_y),
+            (self.size.width() + offset_x, self.size.height() + offset_y),
+            byte,
+        )
+        .await
+    }
 
-        Ok(())
+    /// Fill the area of the framebuffer of the display between `start` and `end` with `byte`.
+    pub(crate) async fn fill_area(
+        &mut self,
+        start: (u8, u8),
+        end: (u8, u8),
+        byte: u8,
+    ) -> Result<(), DisplayError> {
+        let width = end.0.saturating_sub(start.0) as usize;
+
+        if self.addr_mode == AddrMode::Page {
+            for row in (start.1 & !7..end.1).step_by(8) {
+                self.set_draw_area((start.0, row), (end.0, row + 8)).await?;
+                self.interface
+                    .send_data(DataFormat::U8Iter(&mut (0..width).map(|_| byte)))
+                    .await?;
+            }
+            Ok(())
+        } else {
+            let pages = end.1.div_ceil(8).saturating_sub(start.1 / 8) as usize;
+
+            self.set_draw_area(start, end).await?;
+            self.interface
+                .send_data(DataFormat::U8Iter(&mut (0..width * pages).map(|_| byte)))
+                .await
+        }
     }
 
     /// Set the column address in the framebuffer of the display where any sent data should be
@@ -351,8 +760,14 @@ where
             .await
     }
 
+    /// Start of the draw area if every page needs to be positioned separately
+    fn page_origin(&self) -> Option<(u8, u8)> {
+        (self.addr_mode == AddrMode::Page).then_some(self.draw_area_start)
+    }
+
     async fn flush_buffer_chunks(
         interface: &mut DI,
+        page_origin: Option<(u8, u8)>,
         buffer: &[u8],
         disp_width: usize,
         upper_left: (u8, u8),
@@ -369,22 +784,31 @@ where
         let page_lower = upper_left.0 as usize;
         let page_upper = lower_right.0 as usize;
 
-        for c in buffer
+        for (i, c) in buffer
             .chunks(disp_width)
             .skip(starting_page)
             .take(num_pages)
             .map(|s| &s[page_lower..page_upper])
+            .enumerate()
         {
-            interface.send_data(U8(&c)).await?;
+            // Without automatic wrapping every page needs to be positioned explicitly
+            if let Some((column, row)) = page_origin {
+                Command::PageStart((row + i as u8 * 8).into())
+                    .send(interface)
+                    .await?;
+                Command::ColStart(column).send(interface).await?;
+            }
+
+            interface.send_data(U8(c)).await?;
         }
         Ok(())
     }
 }
 
-// SPI-only reset
-impl<SPI, DC, SIZE, MODE> Ssd1306<SPIInterface<SPI, DC>, SIZE, MODE> {
-    /// Reset the display.
-    pub fn reset<RST, DELAY, PinE>(
+// Reset and power sequencing
+impl<DI, SIZE, MODE> Ssd1306<DI, SIZE, MODE> {
+    /// Reset the display using its RES# pin.
+    pub async fn reset<RST, DELAY, PinE>(
         &mut self,
         rst: &mut RST,
         delay: &mut DELAY,
@@ -393,31 +817,112 @@ impl<SPI, DC, SIZE, MODE> Ssd1306<SPIInterface<SPI, DC>, SIZE, MODE> {
         RST: OutputPin<Error = PinE>,
         DELAY: DelayNs,
     {
-        inner_reset(rst, delay)
+        inner_reset(rst, delay).await
     }
 }
 
-fn inner_reset<RST, DELAY, PinE>(rst: &mut RST, delay: &mut DELAY) -> Result<(), Error<(), PinE>>
+async fn inner_reset<RST, DELAY, CommE, PinE>(
+    rst: &mut RST,
+    delay: &mut DELAY,
+) -> Result<(), Error<CommE, PinE>>
 where
     RST: OutputPin<Error = PinE>,
     DELAY: DelayNs,
 {
     rst.set_high().map_err(Error::Pin)?;
-    delay.delay_ms(1);
+    delay.delay_ms(1).await;
     rst.set_low().map_err(Error::Pin)?;
-    delay.delay_ms(10);
+    delay.delay_ms(10).await;
     rst.set_high().map_err(Error::Pin)
 }
 
+impl<DI, SIZE, MODE> Ssd1306<DI, SIZE, MODE>
+where
+    DI: WriteOnlyDataCommand<Error = DisplayError>,
+    SIZE: DisplaySize,
+    Self: DisplayConfig,
+{
+    /// Power up and initialise the display following the datasheet sequence.
+    ///
+    /// VDD must already be stable. The display is reset, then VCC is switched on using the `vcc`
+    /// pin if the panel has one, and the display is initialised and switched on 100ms later
+    /// using the last [`InitConfig`] passed to
+    /// [`init_with_config`](DisplayConfig::init_with_config), or the defaults.
+    pub async fn power_on<RST, VCC, DELAY, PinE>(
+        &mut self,
+        rst: &mut RST,
+        vcc: Option<&mut VCC>,
+        delay: &mut DELAY,
+    ) -> Result<(), Error<<Self as DisplayConfig>::Error, PinE>>
+    where
+        RST: OutputPin<Error = PinE>,
+        VCC: OutputPin<Error = PinE>,
+        DELAY: DelayNs,
+    {
+        inner_reset(rst, delay).await?;
+
+        if let Some(vcc) = vcc {
+            vcc.set_high().map_err(Error::Pin)?;
+        }
+        delay.delay_ms(100).await;
+
+        self.init_with_config(self.config)
+            .await
+            .map_err(Error::Comm)
+    }
+}
+
+impl<DI, SIZE, MODE> Ssd1306<DI, SIZE, MODE>
+where
+    DI: WriteOnlyDataCommand<Error = DisplayError>,
+    SIZE: DisplaySize,
+{
+    /// Power down the display following the datasheet sequence.
+    ///
+    /// The display is switched off and the charge pump disabled, then VCC is switched off using
+    /// the `vcc` pin if the panel has one. This returns 100ms later, after which VDD can be
+    /// switched off safely.
+    pub async fn power_off<VCC, DELAY, PinE>(
+        &mut self,
+        vcc: Option<&mut VCC>,
+        delay: &mut DELAY,
+    ) -> Result<(), Error<DisplayError, PinE>>
+    where
+        VCC: OutputPin<Error = PinE>,
+        DELAY: DelayNs,
+    {
+        self.set_display_on(false).await.map_err(Error::Comm)?;
+        // Only internal supplies can be switched off, the SSD1305 master configuration just
+        // selects the external supply
+        if let Some(cmd @ (Command::ChargePump(_) | Command::DcDc(_))) =
+            self.controller.power_supply(false)
+        {
+            cmd.send(&mut self.interface).await.map_err(Error::Comm)?;
+        }
+
+        if let Some(vcc) = vcc {
+            vcc.set_low().map_err(Error::Pin)?;
+        }
+        delay.delay_ms(100).await;
+
+        Ok(())
+    }
+}
+
 /// A ubiquitous error type for all kinds of problems which could happen when communicating with a
 /// display
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[non_exhaustive]
 pub enum DisplayError {
     /// Invalid data format selected for interface selected
     InvalidFormatError,
     /// Unable to write to bus
     BusWriteError,
+    /// Writing to the I2C bus failed
+    I2cError(i2c::ErrorKind),
+    /// Writing to the SPI bus failed
+    SpiError(spi::ErrorKind),
     /// Unable to assert or de-assert data/command switching signal
     DCError,
     /// Unable to assert chip select signal
@@ -428,6 +933,38 @@ pub enum DisplayError {
     RSError,
     /// Attempted to write to a non-existing pixel outside the display's bounds
     OutOfBoundsError,
+    /// The operation is not supported by the display controller
+    NotSupported,
+}
+
+impl From<i2c::ErrorKind> for DisplayError {
+    fn from(kind: i2c::ErrorKind) -> Self {
+        DisplayError::I2cError(kind)
+    }
+}
+
+impl From<spi::ErrorKind> for DisplayError {
+    fn from(kind: spi::ErrorKind) -> Self {
+        DisplayError::SpiError(kind)
+    }
+}
+
+impl i2c::Error for DisplayError {
+    fn kind(&self) -> i2c::ErrorKind {
+        match self {
+            DisplayError::I2cError(kind) => *kind,
+            _ => i2c::ErrorKind::Other,
+        }
+    }
+}
+
+impl spi::Error for DisplayError {
+    fn kind(&self) -> spi::ErrorKind {
+        match self {
+            DisplayError::SpiError(kind) => *kind,
+            _ => spi::ErrorKind::Other,
+        }
+    }
 }
 
 /// DI specific data format wrapper around slices of various widths
@@ -2,11 +2,16 @@
 
 use crate::{
     command::AddrMode,
+    init_config::InitConfig,
     rotation::DisplayRotation,
     size::{DisplaySize, NewZeroed},
     Ssd1306,
 };
 use crate::{DisplayError, WriteOnlyDataCommand};
+use core::marker::PhantomData;
+
+/// Column range of a page which hasn't changed
+const UNCHANGED: (u8, u8) = (u8::MAX, 0);
 
 /// Buffered graphics mode.
 ///
@@ -14,16 +19,21 @@ use crate::{DisplayError, WriteOnlyDataCommand};
 /// buffer is drawn to by [`set_pixel`](Ssd1306::set_pixel) commands or
 /// [`embedded-graphics`](https://docs.rs/embedded-graphics) commands. The display can then be
 /// updated using the [`flush`](Ssd1306::flush) method.
+///
+/// The changed columns of each page (8 rows of pixels) of the buffer are tracked separately, so a
+/// flush only sends the parts of the display that have changed, even if they are far apart.
+///
+/// The buffer is `SIZE::Buffer` by default, or any other storage passed to
+/// [`into_buffered_graphics_mode_with_buffer`](Ssd1306::into_buffered_graphics_mode_with_buffer).
 #[derive(Clone, Debug)]
-pub struct BufferedGraphicsMode<SIZE>
+pub struct BufferedGraphicsMode<SIZE, B = <SIZE as DisplaySize>::Buffer>
 where
     SIZE: DisplaySize,
 {
-    buffer: SIZE::Buffer,
-    min_x: u8,
-    max_x: u8,
-    min_y: u8,
-    max_y: u8,
+    buffer: B,
+    /// Changed columns of each page of the buffer, as `start..end`
+    dirty: [(u8, u8); 8],
+    size: PhantomData<SIZE>,
 }
 
 impl<SIZE> BufferedGraphicsMode<SIZE>
@@ -32,20 +42,30 @@ where
 {
     /// Create a new buffered graphics mode instance.
     pub(crate) fn new() -> Self {
+        Self::with_buffer(NewZeroed::new_zeroed())
+    }
+}
+
+impl<SIZE, B> BufferedGraphicsMode<SIZE, B>
+where
+    SIZE: DisplaySize,
+    B: AsMut<[u8]>,
+{
+    /// Create a new buffered graphics mode instance using the given buffer.
+    pub(crate) fn with_buffer(buffer: B) -> Self {
         Self {
-            buffer: NewZeroed::new_zeroed(),
-            min_x: 255,
-            max_x: 0,
-            min_y: 255,
-            max_y: 0,
+            buffer,
+            dirty: [UNCHANGED; 8],
+            size: PhantomData,
         }
     }
 }
 
-impl<DI, SIZE> DisplayConfig for Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>
+impl<DI, SIZE, B> DisplayConfig for Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE, B>>
 where
     DI: WriteOnlyDataCommand<Error = DisplayError>,
     SIZE: DisplaySize,
+    B: AsMut<[u8]>,
 {
     type Error = DisplayError;
 
@@ -57,16 +77,21 @@ where
     }
 
     /// Initialise and clear the display in graphics mode.
-    async fn init(&mut self) -> Result<(), Self::Error> {
+    ///
+    /// Returns [`DisplayError::OutOfBoundsError`] if the buffer is too small for the display.
+    async fn init_with_config(&mut self, config: InitConfig) -> Result<(), Self::Error> {
+        self.check_buffer()?;
+        self.config = config;
         self.clear();
         self.init_with_addr_mode(AddrMode::Horizontal).await
     }
 }
 
-impl<DI, SIZE> Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>
+impl<DI, SIZE, B> Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE, B>>
 where
     DI: WriteOnlyDataCommand<Error = DisplayError>,
     SIZE: DisplaySize,
+    B: AsMut<[u8]>,
 {
     /// Clear the display buffer. You need to call `disp.flush()` for any effect on the screen
     pub fn clear(&mut self) {
@@ -74,122 +99,237 @@ where
             *b = 0;
         }
 
-        let (width, height) = self.dimensions();
-        self.mode.min_x = 0;
-        self.mode.max_x = width - 1;
-        self.mode.min_y = 0;
-        self.mode.max_y = height - 1;
+        self.invalidate();
+    }
+
+    /// Convert the display back into [`BasicMode`] and return the buffer, e.g. to draw another
+    /// display using the same buffer.
+    ///
+    /// The display stays initialised. The buffer keeps the pixels drawn last, so
+    /// [`clear`](Self::clear) it before drawing a new frame when converting a display back into the
+    /// buffered graphics mode.
+    ///
+    /// ```rust,no_run
+    /// # use ssd1306_async::{prelude::*, DisplayError, Ssd1306};
+    /// # async fn example<DI>(left: DI, right: DI) -> Result<(), DisplayError>
+    /// # where
+    /// #     DI: WriteOnlyDataCommand<Error = DisplayError>,
+    /// # {
+    /// // A single buffer shared by two displays, which are drawn one after the other
+    /// let mut buffer = [0; 1024];
+    ///
+    /// let mut left = Ssd1306::new(left, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode_with_buffer(&mut buffer);
+    /// left.init().await?;
+    /// left.set_pixel(0, 0, true);
+    /// left.flush().await?;
+    /// let (left, buffer) = left.release_buffer();
+    ///
+    /// let mut right = Ssd1306::new(right, DisplaySize128x64, DisplayRotation::Rotate0)
+    ///     .into_buffered_graphics_mode_with_buffer(buffer);
+    /// right.init().await?;
+    /// right.set_pixel(127, 63, true);
+    /// right.flush().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn release_buffer(self) -> (Ssd1306<DI, SIZE, BasicMode>, B) {
+        let (display, mode) = self.replace_mode(BasicMode);
+        (display, mode.buffer)
+    }
+
+    /// Make sure the buffer can hold the contents of the display
+    fn check_buffer(&mut self) -> Result<(), DisplayError> {
+        let len = self.size.width() as usize * self.size.height() as usize / 8;
+        if self.mode.buffer.as_mut().len() < len {
+            Err(DisplayError::OutOfBoundsError)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Mark the whole buffer as changed, so the next flush redraws the entire display
+    fn invalidate(&mut self) {
+        let (width, height) = (self.size.width(), self.size.height());
+        for (page, dirty) in self.mode.dirty.iter_mut().enumerate() {
+            *dirty = if page < height.div_ceil(8) as usize {
+                (0, width)
+            } else {
+                UNCHANGED
+            };
+        }
+    }
+
+    /// Stop hardware scrolling and redraw the display from the buffer.
+    ///
+    /// Scrolling moves the contents of the display RAM, so it has to be rewritten after scrolling
+    /// has been stopped.
+    pub async fn stop_scroll(&mut self) -> Result<(), DisplayError> {
+        self.disable_scroll().await?;
+        self.invalidate();
+        self.flush().await
     }
 
     /// Write out data to a display.
     ///
-    /// This only updates the parts of the display that have changed since the last flush.
+    /// This only updates the parts of the display that have changed since the last flush. The
+    /// changed columns of each page are sent separately, and consecutive pages with the same
+    /// changed columns are sent at once.
+    ///
+    /// Returns [`DisplayError::OutOfBoundsError`] if the buffer is too small for the display.
     pub async fn flush(&mut self) -> Result<(), DisplayError> {
         // Nothing to do if no pixels have changed since the last update
-        if self.mode.max_x < self.mode.min_x || self.mode.max_y < self.mode.min_y {
+        if self.mode.dirty.iter().all(|(start, end)| start >= end) {
             return Ok(());
         }
-
-        let (width, height) = self.dimensions();
-
-        // Determine which bytes need to be sent
-        let disp_min_x = self.mode.min_x;
-        let disp_min_y = self.mode.min_y;
-
-        let (disp_max_x, disp_max_y) = match self.rotation {
-            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (
-                (self.mode.max_x + 1).min(width),
-                (self.mode.max_y | 7).min(height),
-            ),
-            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (
-                (self.mode.max_x | 7).min(width),
-                (self.mode.max_y + 1).min(height),
-            ),
-        };
-
-        self.mode.min_x = 255;
-        self.mode.max_x = 0;
-        self.mode.min_y = 255;
-        self.mode.max_y = 0;
-
-        // Tell the display to update only the part that has changed
-        let offset_x = match self.rotation {
-            DisplayRotation::Rotate0 | DisplayRotation::Rotate270 => SIZE::OFFSETX,
-            DisplayRotation::Rotate180 | DisplayRotation::Rotate90 => {
-                // If segment remapping is flipped, we need to calculate
-                // the offset from the other edge of the display.
-                SIZE::DRIVER_COLS - SIZE::WIDTH - SIZE::OFFSETX
+        self.check_buffer()?;
+
+        let width = self.size.width() as usize;
+        let offset_x = self.column_offset();
+        let offset_y = self.size.offset_y();
+
+        let mut page = 0;
+        while page < self.mode.dirty.len() {
+            let (start, end) = self.mode.dirty[page];
+            if start >= end {
+                page += 1;
+                continue;
             }
-        };
 
-        match self.rotation {
-            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
-                self.set_draw_area(
-                    (disp_min_x + offset_x, disp_min_y + SIZE::OFFSETY),
-                    (disp_max_x + offset_x, disp_max_y + SIZE::OFFSETY),
-                )
-                .await?;
-
-                Self::flush_buffer_chunks(
-                    &mut self.interface,
-                    self.mode.buffer.as_mut(),
-                    width as usize,
-                    (disp_min_x, disp_min_y),
-                    (disp_max_x, disp_max_y),
-                )
-                .await
-            }
-            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
-                self.set_draw_area(
-                    (disp_min_y + offset_x, disp_min_x + SIZE::OFFSETY),
-                    (disp_max_y + offset_x, disp_max_x + SIZE::OFFSETY),
-                )
-                .await?;
-
-                Self::flush_buffer_chunks(
-                    &mut self.interface,
-                    self.mode.buffer.as_mut(),
-                    height as usize,
-                    (disp_min_y, disp_min_x),
-                    (disp_max_y, disp_max_x),
-                )
-                .await
+            let mut last = page;
+            while self.mode.dirty.get(last + 1) == Some(&(start, end)) {
+                last += 1;
             }
+
+            // The buffer is laid out like the display RAM, so pages and columns map directly
+            let (top, bottom) = (page as u8 * 8, last as u8 * 8);
+            self.set_draw_area(
+                (start + offset_x, top + offset_y),
+                (end + offset_x, bottom + 8 + offset_y),
+            )
+            .await?;
+
+            let page_origin = self.page_origin();
+            Self::flush_buffer_chunks(
+                &mut self.interface,
+                page_origin,
+                self.mode.buffer.as_mut(),
+                width,
+                (start, top),
+                (end, bottom),
+            )
+            .await?;
+
+            // Pages which failed to be sent are kept, so they are retried by the next flush
+            self.mode.dirty[page..=last].fill(UNCHANGED);
+            page = last + 1;
         }
+
+        Ok(())
     }
 
     /// Turn a pixel on or off. A non-zero `value` is treated as on, `0` as off. If the X and Y
     /// coordinates are out of the bounds of the display, this method call is a noop.
     pub fn set_pixel(&mut self, x: u32, y: u32, value: bool) {
         let value = value as u8;
-        let rotation = self.rotation;
+        let Some((idx, bit)) = self.pixel_index(x, y) else {
+            return;
+        };
+
+        if let Some(byte) = self.mode.buffer.as_mut().get_mut(idx) {
+            // Set pixel value in byte
+            // Ref this comment https://stackoverflow.com/questions/47981/how-do-you-set-clear-and-toggle-a-single-bit#comment46654671_47990
+            *byte = *byte & !(1 << bit) | (value << bit);
+            self.mark_changed(idx);
+        }
+    }
 
-        let (idx, bit) = match rotation {
+    /// Set 8 pixels of a column to the bits of `byte`, with the least significant bit at (`x`,
+    /// `y`). Pixels out of the bounds of the display are skipped.
+    pub(crate) fn set_column_byte(&mut self, x: u32, y: u32, byte: u8) {
+        let Some(idx) = self.byte_index(x, y) else {
+            for bit in 0..8 {
+                self.set_pixel(x, y + bit, byte & (1 << bit) != 0);
+            }
+            return;
+        };
+
+        if let Some(target) = self.mode.buffer.as_mut().get_mut(idx) {
+            *target = byte;
+            self.mark_changed(idx);
+        }
+    }
+
+    /// Get 8 pixels of a column as the bits of a byte, with the least significant bit at (`x`,
+    /// `y`). Pixels out of the bounds of the display are off.
+    pub(crate) fn column_byte(&mut self, x: u32, y: u32) -> u8 {
+        match self.byte_index(x, y) {
+            Some(idx) => self.mode.buffer.as_mut().get(idx).copied().unwrap_or(0),
+            None => (0..8).fold(0, |byte, bit| {
+                byte | (self.get_pixel(x, y + bit) as u8) << bit
+            }),
+        }
+    }
+
+    /// Get the index of the byte in the buffer holding the 8 pixels of a column starting at (`x`,
+    /// `y`), or `None` if they aren't all in the same byte of the buffer
+    fn byte_index(&self, x: u32, y: u32) -> Option<usize> {
+        let same_byte = matches!(
+            self.rotation,
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180
+        ) && y % 8 == 0;
+
+        // The last pixel of the column has to be on the display as well
+        let (idx, _) = self.pixel_index(x, y + 7).filter(|_| same_byte)?;
+        Some(idx)
+    }
+
+    /// Keep track of the changed columns of the page containing a byte of the buffer
+    fn mark_changed(&mut self, idx: usize) {
+        let stride = self.size.width() as usize;
+        let (start, end) = &mut self.mode.dirty[idx / stride];
+        let column = (idx % stride) as u8;
+        *start = (*start).min(column);
+        *end = (*end).max(column + 1);
+    }
+
+    /// Get whether a pixel is on in the buffer. Pixels out of the bounds of the display are off.
+    pub(crate) fn get_pixel(&mut self, x: u32, y: u32) -> bool {
+        let Some((idx, bit)) = self.pixel_index(x, y) else {
+            return false;
+        };
+
+        // The buffer is only required to implement `AsMut`
+        self.mode
+            .buffer
+            .as_mut()
+            .get(idx)
+            .is_some_and(|byte| byte & (1 << bit) != 0)
+    }
+
+    /// Get the index of the byte in the buffer containing a pixel, and the bit in the byte, or
+    /// `None` if the pixel is outside the display
+    fn pixel_index(&self, x: u32, y: u32) -> Option<(usize, u32)> {
+        // The buffer may be larger than the display
+        let (width, height) = self.dimensions();
+        if x >= width as u32 || y >= height as u32 {
+            return None;
+        }
+
+        let stride = self.size.width() as usize;
+        match self.rotation {
             DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
-                let idx = ((y as usize) / 8 * SIZE::WIDTH as usize) + (x as usize);
+                let idx = ((y as usize) / 8 * stride) + (x as usize);
                 let bit = y % 8;
 
-                (idx, bit)
+                Some((idx, bit))
             }
             DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
-                let idx = ((x as usize) / 8 * SIZE::WIDTH as usize) + (y as usize);
+                let idx = ((x as usize) / 8 * stride) + (y as usize);
                 let bit = x % 8;
 
-                (idx, bit)
+                Some((idx, bit))
             }
-        };
-
-        if let Some(byte) = self.mode.buffer.as_mut().get_mut(idx) {
-            // Keep track of max and min values
-            self.mode.min_x = self.mode.min_x.min(x as u8);
-            self.mode.max_x = self.mode.max_x.max(x as u8);
-
-            self.mode.min_y = self.mode.min_y.min(y as u8);
-            self.mode.max_y = self.mode.max_y.max(y as u8);
-
-            // Set pixel value in byte
-            // Ref this comment https://stackoverflow.com/questions/47981/how-do-you-set-clear-and-toggle-a-single-bit#comment46654671_47990
-            *byte = *byte & !(1 << bit) | (value << bit)
         }
     }
 }
@@ -203,13 +343,14 @@ use embedded_graphics_core::{
     Pixel,
 };
 
-use super::DisplayConfig;
+use super::{BasicMode, DisplayConfig};
 
 #[cfg(feature = "graphics")]
-impl<DI, SIZE> DrawTarget for Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>
+impl<DI, SIZE, B> DrawTarget for Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE, B>>
 where
     DI: WriteOnlyDataCommand<Error = DisplayError>,
     SIZE: DisplaySize,
+    B: AsMut<[u8]>,
 {
     type Color = BinaryColor;
     type Error = DisplayError;
@@ -232,10 +373,11 @@ where
 }
 
 #[cfg(feature = "graphics")]
-impl<DI, SIZE> OriginDimensions for Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE>>
+impl<DI, SIZE, B> OriginDimensions for Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE, B>>
 where
     DI: WriteOnlyDataCommand<Error = DisplayError>,
     SIZE: DisplaySize,
+    B: AsMut<[u8]>,
 {
     fn size(&self) -> Size {
         let (w, h) = self.dimensions();
@@ -0,0 +1,359 @@
+//! Text console drawn into the buffer of the buffered graphics mode.
+
+use super::{
+    terminal::{Cursor, CursorWrapEvent},
+    Attributes, BufferedGraphicsMode, Overflow, TerminalModeError,
+};
+use crate::{
+    font::{glyph_or_fallback, Font8x8, TerminalFont},
+    size::DisplaySize,
+    DisplayError, Ssd1306, WriteOnlyDataCommand,
+};
+use core::{fmt, ops::Range};
+
+/// A text console drawn into the buffer of [`BufferedGraphicsMode`].
+///
+/// The console prints text like [`TerminalMode`](super::TerminalMode) does, but draws the
+/// characters into a rectangle of the display buffer instead of sending them to the display. This
+/// allows showing text next to graphics; everything is sent to the display by the next
+/// [`flush`](Ssd1306::flush).
+///
+/// The console only keeps the cursor and settings, so use [`writer`](Self::writer) to print to a
+/// display:
+///
+/// ```rust,no_run
+/// # use ssd1306_async::{mode::TextConsole, prelude::*, DisplayError, Ssd1306};
+/// use core::fmt::Write;
+///
+/// # async fn example<DI>(interface: DI) -> Result<(), DisplayError>
+/// # where
+/// #     DI: WriteOnlyDataCommand<Error = DisplayError>,
+/// # {
+/// let mut display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
+///     .into_buffered_graphics_mode();
+/// display.init().await?;
+///
+/// // A text log in the top half of the display
+/// let mut console = TextConsole::new((0, 0), (128, 32));
+/// writeln!(console.writer(&mut display), "RSSI: {}", -71).unwrap();
+///
+/// display.flush().await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// `\n` moves to the start of the next line and `\r` to the start of the current line. Other
+/// control characters are ignored.
+#[derive(Copy, Clone, Debug)]
+pub struct TextConsole<F = Font8x8> {
+    origin: (u8, u8),
+    size: (u8, u8),
+    cursor: Cursor,
+    font: F,
+    overflow: Overflow,
+    attributes: Attributes,
+}
+
+impl TextConsole {
+    /// Create a console using the default [`Font8x8`], which occupies the rectangle of `size`
+    /// pixels with its top left corner at `origin`.
+    ///
+    /// The coordinates are the same as for [`set_pixel`](Ssd1306::set_pixel), so they depend on
+    /// the rotation of the display. Parts of the rectangle outside the display are not drawn.
+    pub fn new(origin: (u8, u8), size: (u8, u8)) -> Self {
+        Self::with_font(Font8x8, origin, size)
+    }
+}
+
+impl<F> TextConsole<F>
+where
+    F: TerminalFont,
+{
+    /// Create a console using the given font, which occupies the rectangle of `size` pixels with
+    /// its top left corner at `origin`.
+    pub fn with_font(font: F, origin: (u8, u8), size: (u8, u8)) -> Self {
+        // There's always room for at least one character, which may be cut off
+        let width = (size.0 / F::WIDTH).max(1);
+        let height = (size.1 / (F::PAGES * 8)).max(1);
+
+        Self {
+            origin,
+            size,
+            cursor: Cursor::new(width, height),
+            font,
+            overflow: Overflow::default(),
+            attributes: Attributes::new(),
+        }
+    }
+
+    /// Get the number of characters that fit into the console, as (columns, rows).
+    pub fn char_dimensions(&self) -> (u8, u8) {
+        self.cursor.get_dimensions()
+    }
+
+    /// Get the current cursor position, in character coordinates.
+    /// This is the (column, row) that the next character will be written to.
+    pub fn position(&self) -> (u8, u8) {
+        self.cursor.get_position()
+    }
+
+    /// Set the cursor position, in character coordinates.
+    /// This is the (column, row) that the next character will be written to.
+    /// If the position is out of bounds, an Err will be returned.
+    pub fn set_position(&mut self, column: u8, row: u8) -> Result<(), TerminalModeError> {
+        let (width, height) = self.cursor.get_dimensions();
+        if column >= width || row >= height {
+            Err(TerminalModeError::OutOfBounds)
+        } else {
+            self.cursor.set_position(column, row);
+            Ok(())
+        }
+    }
+
+    /// Set what happens when the cursor moves past the last line of the console.
+    ///
+    /// Scrolling moves the pixels of the console rectangle in the buffer, so it works with every
+    /// rotation.
+    pub fn set_overflow(&mut self, overflow: Overflow) {
+        self.overflow = overflow;
+    }
+
+    /// Get what happens when the cursor moves past the last line of the console.
+    pub fn overflow(&self) -> Overflow {
+        self.overflow
+    }
+
+    /// Set the attributes used to draw the following characters.
+    pub fn set_attributes(&mut self, attributes: Attributes) {
+        self.attributes = attributes;
+    }
+
+    /// Get the attributes used to draw the following characters.
+    pub fn attributes(&self) -> Attributes {
+        self.attributes
+    }
+
+    /// Get a writer which prints to the console in the buffer of `display`.
+    pub fn writer<'a, DI, SIZE, B>(
+        &'a mut self,
+        display: &'a mut Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE, B>>,
+    ) -> ConsoleWriter<'a, DI, SIZE, F, B>
+    where
+        DI: WriteOnlyDataCommand<Error = DisplayError>,
+        SIZE: DisplaySize,
+        B: AsMut<[u8]>,
+    {
+        ConsoleWriter {
+            console: self,
+            display,
+        }
+    }
+
+    /// Get 8 pixels of a column of the console, starting at (`x`, `y`) relative to its origin, as
+    /// the bits of a byte
+    fn get_byte<DI, SIZE, B>(
+        &self,
+        display: &mut Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE, B>>,
+        x: u32,
+        y: u32,
+    ) -> u8
+    where
+        DI: WriteOnlyDataCommand<Error = DisplayError>,
+        SIZE: DisplaySize,
+        B: AsMut<[u8]>,
+    {
+        let (origin_x, origin_y) = self.origin;
+        let (width, height) = self.size;
+
+        if x < width as u32 && y + 8 <= height as u32 {
+            display.column_byte(origin_x as u32 + x, origin_y as u32 + y)
+        } else {
+            (0..8).fold(0, |byte, bit| {
+                byte | (self.get_pixel(display, x, y + bit) as u8) << bit
+            })
+        }
+    }
+
+    /// Set 8 pixels of a column of the console to the bits of `byte`, starting at (`x`, `y`)
+    /// relative to its origin
+    fn set_byte<DI, SIZE, B>(
+        &self,
+        display: &mut Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE, B>>,
+        x: u32,
+        y: u32,
+        byte: u8,
+    ) where
+        DI: WriteOnlyDataCommand<Error = DisplayError>,
+        SIZE: DisplaySize,
+        B: AsMut<[u8]>,
+    {
+        let (origin_x, origin_y) = self.origin;
+        let (width, height) = self.size;
+
+        if x < width as u32 && y + 8 <= height as u32 {
+            display.set_column_byte(origin_x as u32 + x, origin_y as u32 + y, byte);
+        } else {
+            for bit in 0..8 {
+                self.set_pixel(display, x, y + bit, byte & (1 << bit) != 0);
+            }
+        }
+    }
+
+    /// Get a pixel of the console, relative to its origin
+    fn get_pixel<DI, SIZE, B>(
+        &self,
+        display: &mut Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE, B>>,
+        x: u32,
+        y: u32,
+    ) -> bool
+    where
+        DI: WriteOnlyDataCommand<Error = DisplayError>,
+        SIZE: DisplaySize,
+        B: AsMut<[u8]>,
+    {
+        self.display_position(x, y)
+            .is_some_and(|(x, y)| display.get_pixel(x, y))
+    }
+
+    /// Set a pixel of the console, relative to its origin
+    fn set_pixel<DI, SIZE, B>(
+        &self,
+        display: &mut Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE, B>>,
+        x: u32,
+        y: u32,
+        value: bool,
+    ) where
+        DI: WriteOnlyDataCommand<Error = DisplayError>,
+        SIZE: DisplaySize,
+        B: AsMut<[u8]>,
+    {
+        if let Some((x, y)) = self.display_position(x, y) {
+            display.set_pixel(x, y, value);
+        }
+    }
+
+    /// Get the display coordinates of a pixel of the console, or `None` if it's outside the
+    /// console. Pixels outside the display are skipped by the display.
+    fn display_position(&self, x: u32, y: u32) -> Option<(u32, u32)> {
+        let (origin_x, origin_y) = self.origin;
+        let (width, height) = self.size;
+
+        (x < width as u32 && y < height as u32)
+            .then_some((origin_x as u32 + x, origin_y as u32 + y))
+    }
+}
+
+/// Prints to a [`TextConsole`] in the buffer of a display.
+///
+/// This implements [`core::fmt::Write`], so `write!` can be used to print formatted text. Nothing
+/// is sent to the display until it is flushed, so writing never fails.
+pub struct ConsoleWriter<'a, DI, SIZE, F, B = <SIZE as DisplaySize>::Buffer>
+where
+    SIZE: DisplaySize,
+{
+    console: &'a mut TextConsole<F>,
+    display: &'a mut Ssd1306<DI, SIZE, BufferedGraphicsMode<SIZE, B>>,
+}
+
+impl<DI, SIZE, F, B> ConsoleWriter<'_, DI, SIZE, F, B>
+where
+    DI: WriteOnlyDataCommand<Error = DisplayError>,
+    SIZE: DisplaySize,
+    F: TerminalFont,
+    B: AsMut<[u8]>,
+{
+    /// Print a character to the console
+    pub fn print_char(&mut self, c: char) {
+        match c {
+            '\n' => {
+                let scroll = self.console.overflow == Overflow::Scroll;
+                if let CursorWrapEvent::Scroll = self.console.cursor.advance_line(scroll) {
+                    self.scroll_line();
+                }
+            }
+            '\r' => {
+                let (_, row) = self.console.cursor.get_position();
+                self.console.cursor.set_position(0, row);
+            }
+            _ if c.is_control() => {}
+            _ => {
+                self.draw_char(c);
+
+                let scroll = self.console.overflow == Overflow::Scroll;
+                if let Some(CursorWrapEvent::Scroll) = self.console.cursor.advance(scroll) {
+                    self.scroll_line();
+                }
+            }
+        }
+    }
+
+    /// Clear the console and reset the cursor to the top left corner
+    pub fn clear(&mut self) {
+        let (width, height) = self.console.size;
+        self.fill(0..width as u32, 0..height as u32);
+        self.console.cursor.set_position(0, 0);
+    }
+
+    /// Draw a character at the cursor, without moving the cursor
+    fn draw_char(&mut self, c: char) {
+        let console = &*self.console;
+        let display = &mut *self.display;
+        let (column, row) = console.cursor.get_position();
+        let x = column as u32 * F::WIDTH as u32;
+        let y = row as u32 * F::PAGES as u32 * 8;
+        let attributes = console.attributes;
+        let glyph = glyph_or_fallback(&console.font, c);
+
+        for page in 0..F::PAGES {
+            for col in 0..F::WIDTH {
+                let byte = attributes.glyph_byte::<F>(glyph, col, page) ^ attributes.invert_mask();
+                console.set_byte(display, x + col as u32, y + page as u32 * 8, byte);
+            }
+        }
+    }
+
+    /// Scroll the console up by a line, and clear the new last line
+    fn scroll_line(&mut self) {
+        let (width, height) = self.console.size;
+        let (width, height) = (width as u32, height as u32);
+        let line = F::PAGES as u32 * 8;
+        let (_, rows) = self.console.cursor.get_dimensions();
+        let text_height = (rows as u32 * line).min(height);
+
+        // Lines are a whole number of bytes high
+        for y in (0..text_height.saturating_sub(line)).step_by(8) {
+            for x in 0..width {
+                let byte = self.console.get_byte(self.display, x, y + line);
+                self.console.set_byte(self.display, x, y, byte);
+            }
+        }
+
+        // Clear the last line, along with any rows below it which don't fit a whole line
+        self.fill(0..width, text_height.saturating_sub(line)..height);
+    }
+
+    /// Clear a rectangle of the console, in pixels relative to its origin
+    fn fill(&mut self, xs: Range<u32>, ys: Range<u32>) {
+        for y in ys {
+            for x in xs.clone() {
+                self.console.set_pixel(self.display, x, y, false);
+            }
+        }
+    }
+}
+
+impl<DI, SIZE, F, B> fmt::Write for ConsoleWriter<'_, DI, SIZE, F, B>
+where
+    DI: WriteOnlyDataCommand<Error = DisplayError>,
+    SIZE: DisplaySize,
+    F: TerminalFont,
+    B: AsMut<[u8]>,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            self.print_char(c);
+        }
+
+        Ok(())
+    }
+}
@@ -1,11 +1,16 @@
 //! Display modes.
 
 mod buffered_graphics;
+mod console;
 mod terminal;
 
-use crate::{command::AddrMode, rotation::DisplayRotation, size::DisplaySize, Ssd1306};
+use crate::{
+    command::AddrMode, init_config::InitConfig, rotation::DisplayRotation, size::DisplaySize,
+    Ssd1306,
+};
 use crate::{DisplayError, WriteOnlyDataCommand};
 pub use buffered_graphics::*;
+pub use console::*;
 pub use terminal::*;
 
 /// Common functions to all display modes.
@@ -17,7 +22,12 @@ pub trait DisplayConfig {
     async fn set_rotation(&mut self, rotation: DisplayRotation) -> Result<(), Self::Error>;
 
     /// Initialise and configure the display for the given mode.
-    async fn init(&mut self) -> Result<(), Self::Error>;
+    async fn init(&mut self) -> Result<(), Self::Error> {
+        self.init_with_config(InitConfig::new()).await
+    }
+
+    /// Initialise and configure the display for the given mode, using custom init settings.
+    async fn init_with_config(&mut self, config: InitConfig) -> Result<(), Self::Error>;
 }
 
 /// A mode with no additional functionality beyond that provided by the base [`Ssd1306`] struct.
@@ -31,11 +41,24 @@ where
 {
     /// Clear the display.
     pub async fn clear(&mut self) -> Result<(), DisplayError> {
-        self.set_draw_area((0, 0), self.dimensions()).await?;
+        self.fill(0).await
+    }
 
-        // TODO: If const generics allows this, replace `1024` with computed W x H value for current
-        // `SIZE`.
-        self.draw(&[0u8; 1024]).await
+    /// Set every byte of the display to `byte`. Each byte is a column of 8 pixels, with the least
+    /// significant bit at the top, so e.g. `0xFF` turns on every pixel and `0x55` draws horizontal
+    /// stripes.
+    ///
+    /// Only the part of the display RAM shown by the panel is written, taking its offsets into
+    /// account.
+    pub async fn fill(&mut self, byte: u8) -> Result<(), DisplayError> {
+        self.fill_display(byte).await
+    }
+
+    /// Stop hardware scrolling.
+    ///
+    /// Scrolling moves the contents of the display RAM, so it has to be redrawn afterwards.
+    pub async fn stop_scroll(&mut self) -> Result<(), DisplayError> {
+        self.disable_scroll().await
     }
 }
 
@@ -53,7 +76,8 @@ where
     }
 
     /// Initialise in horizontal addressing mode.
-    async fn init(&mut self) -> Result<(), Self::Error> {
+    async fn init_with_config(&mut self, config: InitConfig) -> Result<(), Self::Error> {
+        self.config = config;
         self.init_with_addr_mode(AddrMode::Horizontal).await?;
         Ok(())
     }
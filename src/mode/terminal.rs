@@ -1,39 +1,79 @@
-use crate::{command::AddrMode, mode::DisplayConfig, rotation::DisplayRotation, size::*, Ssd1306};
-use crate::{DisplayError, WriteOnlyDataCommand};
-use core::{cmp::min, fmt};
+mod ansi;
+mod cells;
+
+use crate::{
+    command::{AddrMode, Command},
+    font::{glyph_or_fallback, Font8x8, TerminalFont},
+    init_config::InitConfig,
+    mode::DisplayConfig,
+    rotation::DisplayRotation,
+    size::*,
+    Ssd1306,
+};
+use crate::{DataFormat, DisplayError, WriteOnlyDataCommand};
+use ansi::{Action, Csi, Parser};
+pub use cells::TerminalCell;
+use cells::{Cell, Cells};
+use core::{cmp::min, fmt, ops::Range};
+#[cfg(feature = "io")]
+use embedded_io_async::{ErrorKind, ErrorType, Write};
+
+/// Size of the buffer formatted text is printed from
+const FMT_CHUNK_LEN: usize = 32;
+
+/// Distance between tab stops, in characters
+const TAB_WIDTH: u8 = 8;
 
 /// Extends the [`DisplaySize`](crate::size::DisplaySize) trait
 /// to include number of characters that can fit on the display.
 pub trait TerminalDisplaySize: DisplaySize {
-    /// The number of characters that can fit on the display at once (w * h / (8 * 8))
-    const CHAR_NUM: u8;
+    /// The number of characters of the default [`Font8x8`] that can fit on the display at once
+    /// (w * h / (8 * 8))
+    ///
+    /// Use [`char_num`] to get the number of characters for other fonts.
+    const CHAR_NUM: u8 = char_num::<Self, Font8x8>() as u8;
 }
 
-impl TerminalDisplaySize for DisplaySize128x64 {
-    const CHAR_NUM: u8 = 128;
+/// The number of characters of the font `F` that can fit on a display of size `SIZE` at once,
+/// without scaling the text.
+///
+/// Use [`Ssd1306::char_dimensions`] to get the number of characters for the current text scale and
+/// rotation.
+pub const fn char_num<SIZE, F>() -> u16
+where
+    SIZE: TerminalDisplaySize + ?Sized,
+    F: TerminalFont,
+{
+    (SIZE::WIDTH / F::WIDTH) as u16 * (SIZE::HEIGHT / (F::PAGES * 8)) as u16
 }
 
-impl TerminalDisplaySize for DisplaySize128x32 {
-    const CHAR_NUM: u8 = 64;
-}
+impl TerminalDisplaySize for DisplaySize128x64 {}
 
-impl TerminalDisplaySize for DisplaySize96x16 {
-    const CHAR_NUM: u8 = 24;
-}
+impl TerminalDisplaySize for DisplaySize128x32 {}
 
-impl TerminalDisplaySize for DisplaySize72x40 {
-    const CHAR_NUM: u8 = 45;
-}
+impl TerminalDisplaySize for DisplaySize96x16 {}
+
+impl TerminalDisplaySize for DisplaySize72x40 {}
+
+impl TerminalDisplaySize for DisplaySize64x48 {}
+
+impl TerminalDisplaySize for DisplaySize64x32 {}
 
-impl TerminalDisplaySize for DisplaySize64x48 {
-    const CHAR_NUM: u8 = 48;
+impl<const W: u8, const H: u8, const OX: u8, const OY: u8> TerminalDisplaySize
+    for CustomSize<W, H, OX, OY>
+{
 }
 
-/// Contains the new row that the cursor has wrapped around to
-struct CursorWrapEvent(u8);
+/// Where the cursor has moved to after wrapping to the next line
+pub(super) enum CursorWrapEvent {
+    /// The cursor moved to the start of the given row
+    Row(u8),
+    /// The cursor stayed on the last row, so the text has to be scrolled up by one line
+    Scroll,
+}
 
 #[derive(Copy, Clone, Debug)]
-struct Cursor {
+pub(super) struct Cursor {
     col: u8,
     row: u8,
     width: u8,
@@ -41,9 +81,7 @@ struct Cursor {
 }
 
 impl Cursor {
-    pub fn new(width_pixels: u8, height_pixels: u8) -> Self {
-        let width = width_pixels / 8;
-        let height = height_pixels / 8;
+    pub fn new(width: u8, height: u8) -> Self {
         Cursor {
             col: 0,
             row: 0,
@@ -53,13 +91,12 @@ impl Cursor {
     }
 
     /// Advances the logical cursor by one character.
-    /// Returns a value indicating if this caused the cursor to wrap to the next line or the next
-    /// screen.
-    pub fn advance(&mut self) -> Option<CursorWrapEvent> {
+    /// Returns a value indicating if this caused the cursor to wrap to the next line, the next
+    /// screen, or the text to scroll if `scroll` is set.
+    pub fn advance(&mut self, scroll: bool) -> Option<CursorWrapEvent> {
         self.col = (self.col + 1) % self.width;
         if self.col == 0 {
-            self.row = (self.row + 1) % self.height;
-            Some(CursorWrapEvent(self.row))
+            Some(self.next_row(scroll))
         } else {
             None
         }
@@ -67,10 +104,22 @@ impl Cursor {
 
     /// Advances the logical cursor to the start of the next line
     /// Returns a value indicating the now active line
-    pub fn advance_line(&mut self) -> CursorWrapEvent {
-        self.row = (self.row + 1) % self.height;
+    pub fn advance_line(&mut self, scroll: bool) -> CursorWrapEvent {
         self.col = 0;
-        CursorWrapEvent(self.row)
+        self.next_row(scroll)
+    }
+
+    /// Moves the logical cursor down a row, staying on the last row if `scroll` is set
+    fn next_row(&mut self, scroll: bool) -> CursorWrapEvent {
+        if self.row + 1 < self.height {
+            self.row += 1;
+            CursorWrapEvent::Row(self.row)
+        } else if scroll {
+            CursorWrapEvent::Scroll
+        } else {
+            self.row = 0;
+            CursorWrapEvent::Row(self.row)
+        }
     }
 
     /// Sets the position of the logical cursor arbitrarily.
@@ -93,6 +142,7 @@ impl Cursor {
 
 /// Errors which can occur when interacting with the terminal mode
 #[derive(Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum TerminalModeError {
     /// An error occurred in the underlying interface layer
     InterfaceError(DisplayError),
@@ -100,6 +150,10 @@ pub enum TerminalModeError {
     Uninitialized,
     /// A location was specified outside the bounds of the screen
     OutOfBounds,
+    /// Formatting a value failed
+    FormatError,
+    /// Showing the cursor needs the contents of the cells, which aren't kept
+    NoCellStorage,
 }
 
 impl core::fmt::Debug for TerminalModeError {
@@ -108,6 +162,19 @@ impl core::fmt::Debug for TerminalModeError {
             Self::InterfaceError(_) => "InterfaceError".fmt(f),
             Self::Uninitialized => "Uninitialized".fmt(f),
             Self::OutOfBounds => "OutOfBound".fmt(f),
+            Self::FormatError => "FormatError".fmt(f),
+            Self::NoCellStorage => "NoCellStorage".fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "io")]
+impl embedded_io_async::Error for TerminalModeError {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::OutOfBounds => ErrorKind::InvalidInput,
+            Self::NoCellStorage => ErrorKind::Unsupported,
+            _ => ErrorKind::Other,
         }
     }
 }
@@ -123,23 +190,303 @@ impl<T> IntoTerminalModeResult<T> for Result<T, DisplayError> {
     }
 }
 
+/// What happens when the cursor moves past the last line of the display in terminal mode
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Overflow {
+    /// Scroll the text up by a line, like a console.
+    ///
+    /// The display start line is moved, so only the new line has to be cleared. As this can't
+    /// move text sideways, rotated displays always wrap.
+    #[default]
+    Scroll,
+    /// Continue at the top of the display, overwriting the text there
+    Wrap,
+}
+
+/// Scale of the characters drawn in terminal mode.
+///
+/// The glyphs of the font are enlarged in software, so every pixel becomes a block of 2x2 or 4x4
+/// pixels, or of 2x1 or 1x2 pixels to only stretch the characters in one direction. The
+/// controller's [zoom](Ssd1306::set_zoom) isn't used, as it enlarges the whole display.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TextScale {
+    /// Draw characters at the size of the font
+    #[default]
+    X1,
+    /// Draw characters twice as wide and high
+    X2,
+    /// Draw characters four times as wide and high
+    X4,
+    /// Draw characters twice as wide, at the height of the font
+    DoubleWidth,
+    /// Draw characters twice as high, at the width of the font
+    DoubleHeight,
+}
+
+impl TextScale {
+    /// Number of display pixels per font pixel, as (horizontally, vertically)
+    fn factors(self) -> (u8, u8) {
+        match self {
+            TextScale::X1 => (1, 1),
+            TextScale::X2 => (2, 2),
+            TextScale::X4 => (4, 4),
+            TextScale::DoubleWidth => (2, 1),
+            TextScale::DoubleHeight => (1, 2),
+        }
+    }
+}
+
+/// Attributes changing how characters are drawn in terminal mode.
+///
+/// The attributes are applied to the glyphs of the font, so they work with every font and
+/// rotation.
+///
+/// ```rust
+/// use ssd1306_async::mode::Attributes;
+///
+/// let selected = Attributes::new().inverse(true).underline(true);
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Attributes {
+    // Kept as bits, as the attributes of every character on the display are stored
+    bits: u8,
+}
+
+impl Attributes {
+    const INVERSE: u8 = 1 << 0;
+    const UNDERLINE: u8 = 1 << 1;
+    const STRIKETHROUGH: u8 = 1 << 2;
+
+    /// Create attributes which draw characters unchanged
+    pub const fn new() -> Self {
+        Self { bits: 0 }
+    }
+
+    /// Invert all pixels of the character cells, e.g. to highlight a selected menu entry.
+    pub const fn inverse(self, enable: bool) -> Self {
+        self.with(Self::INVERSE, enable)
+    }
+
+    /// Draw a line along the bottom pixel row of the character cells.
+    pub const fn underline(self, enable: bool) -> Self {
+        self.with(Self::UNDERLINE, enable)
+    }
+
+    /// Draw a line through the middle of the character cells.
+    pub const fn strikethrough(self, enable: bool) -> Self {
+        self.with(Self::STRIKETHROUGH, enable)
+    }
+
+    const fn with(self, bit: u8, enable: bool) -> Self {
+        Self {
+            bits: if enable {
+                self.bits | bit
+            } else {
+                self.bits & !bit
+            },
+        }
+    }
+
+    const fn has(&self, bit: u8) -> bool {
+        self.bits & bit != 0
+    }
+
+    /// Get the byte at `col` in `page` of a glyph, with the line attributes drawn onto it
+    pub(super) fn glyph_byte<F: TerminalFont>(&self, glyph: &[u8], col: u8, page: u8) -> u8 {
+        let byte = glyph
+            .get(page as usize * F::WIDTH as usize + col as usize)
+            .copied()
+            .unwrap_or(0);
+        byte | self.lines::<F>(page)
+    }
+
+    /// Get the mask which is XORed with the bytes of a character cell
+    pub(super) fn invert_mask(&self) -> u8 {
+        if self.has(Self::INVERSE) {
+            0xFF
+        } else {
+            0
+        }
+    }
+
+    /// Get the bits of a glyph page which are set by the line attributes
+    fn lines<F: TerminalFont>(&self, page: u8) -> u8 {
+        let row = |enable: bool, y: u8| {
+            if enable && y / 8 == page {
+                1 << (y % 8)
+            } else {
+                0
+            }
+        };
+
+        row(self.has(Self::UNDERLINE), F::PAGES * 8 - 1)
+            | row(self.has(Self::STRIKETHROUGH), F::PAGES * 4 - 1)
+    }
+}
+
+/// How the cursor is shown in terminal mode
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CursorStyle {
+    /// Invert the whole character cell
+    Block,
+    /// Invert the bottom pixel row of the character cell
+    Underline,
+    /// Don't show the cursor
+    #[default]
+    Hidden,
+}
+
+/// Cursor state stored by the save cursor escape sequences
+#[derive(Copy, Clone, Debug, Default)]
+struct SavedCursor {
+    position: (u8, u8),
+    attributes: Attributes,
+}
+
 /// Terminal mode.
+///
+/// Text is drawn using a [`TerminalFont`], which is [`Font8x8`] unless another font is passed to
+/// [`into_terminal_mode_with_font`](Ssd1306::into_terminal_mode_with_font).
+///
+/// Besides printable characters, a subset of the ANSI (VT100) control characters and escape
+/// sequences is understood, so the display can be driven like a small serial terminal:
+///
+/// | Sequence | Function |
+/// |----------|----------|
+/// | `\n` | Move to the start of the next line, scrolling if needed |
+/// | `\r` | Move to the start of the line |
+/// | `\t` | Move to the next tab stop (every 8 columns) |
+/// | `\x08` (backspace) | Move one column left |
+/// | `\x0c` (form feed) | Clear the display |
+/// | `ESC [ n A` / `B` / `C` / `D` | Move the cursor up / down / right / left by `n` |
+/// | `ESC [ row ; col H` or `f` | Move the cursor to a position, starting at 1 |
+/// | `ESC [ n J` | Erase below (0), above (1) or all of (2) the display |
+/// | `ESC [ n K` | Erase to the right of (0), left of (1) or the whole (2) line |
+/// | `ESC [ 7 m` / `ESC [ 27 m` | Inverse on / off |
+/// | `ESC [ 4 m` / `ESC [ 24 m` | Underline on / off |
+/// | `ESC [ 9 m` / `ESC [ 29 m` | Strikethrough on / off |
+/// | `ESC [ 0 m` | Reset all [`Attributes`] |
+/// | `ESC 7` or `ESC [ s` | Save the cursor position and attributes |
+/// | `ESC 8` or `ESC [ u` | Restore the saved cursor position and attributes |
+///
+/// Cursor movements stop at the edges of the display. Other control characters and escape
+/// sequences are ignored.
+///
+/// The contents of the character cells are kept in `C` to show the cursor, see
+/// [`with_cells`](Ssd1306::with_cells). No cells are kept by default.
 #[derive(Copy, Clone, Debug)]
-pub struct TerminalMode {
+pub struct TerminalMode<F = Font8x8, C = [TerminalCell; 0]> {
     cursor: Option<Cursor>,
+    font: F,
+    overflow: Overflow,
+    text_scale: TextScale,
+    /// Number of display RAM rows the text has been scrolled up by
+    scroll: u8,
+    parser: Parser,
+    attributes: Attributes,
+    saved_cursor: SavedCursor,
+    cells: Cells<C>,
+    cursor_style: CursorStyle,
+    /// Position of the cell the cursor is currently drawn over
+    cursor_drawn: Option<(u8, u8)>,
+    /// Start of a UTF-8 sequence which continues in the next write, and its length
+    #[cfg(feature = "io")]
+    utf8_pending: ([u8; 3], u8),
 }
 
 impl TerminalMode {
     /// Create a new terminal mode config instance.
     pub fn new() -> Self {
-        Self { cursor: None }
+        Self::with_font(Font8x8)
+    }
+}
+
+impl<F> TerminalMode<F> {
+    /// Create a new terminal mode config instance using the given font.
+    pub fn with_font(font: F) -> Self {
+        Self {
+            cursor: None,
+            font,
+            overflow: Overflow::default(),
+            text_scale: TextScale::default(),
+            scroll: 0,
+            parser: Parser::default(),
+            attributes: Attributes::new(),
+            saved_cursor: SavedCursor::default(),
+            cells: Cells::new([]),
+            cursor_style: CursorStyle::default(),
+            cursor_drawn: None,
+            #[cfg(feature = "io")]
+            utf8_pending: ([0; 3], 0),
+        }
+    }
+}
+
+impl Default for TerminalMode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F, C> TerminalMode<F, C>
+where
+    C: AsMut<[TerminalCell]>,
+{
+    /// Keep the contents of the character cells in `cells` instead
+    fn with_cells<C2>(self, cells: C2) -> TerminalMode<F, C2>
+    where
+        C2: AsMut<[TerminalCell]>,
+    {
+        TerminalMode {
+            cursor: self.cursor,
+            font: self.font,
+            overflow: self.overflow,
+            text_scale: self.text_scale,
+            scroll: self.scroll,
+            parser: self.parser,
+            attributes: self.attributes,
+            saved_cursor: self.saved_cursor,
+            cells: self.cells.with_storage(cells),
+            cursor_style: self.cursor_style,
+            cursor_drawn: None,
+            #[cfg(feature = "io")]
+            utf8_pending: self.utf8_pending,
+        }
     }
 }
 
-impl<DI, SIZE> DisplayConfig for Ssd1306<DI, SIZE, TerminalMode>
+impl<DI, SIZE, F, C> Ssd1306<DI, SIZE, TerminalMode<F, C>>
 where
     DI: WriteOnlyDataCommand<Error = DisplayError>,
     SIZE: TerminalDisplaySize,
+    C: AsMut<[TerminalCell]>,
+{
+    /// Keep the contents of the character cells in `cells`, so the cursor can be drawn over them
+    /// and removed again. The display RAM can't be read, so the cursor is only shown over the
+    /// first `cells.len()` cells, row by row.
+    ///
+    /// The text already shown isn't known, so call this before printing, e.g.
+    /// `display.into_terminal_mode().with_cells([TerminalCell::BLANK; 128])` to keep all cells of
+    /// a 128x64 display using the default font.
+    pub fn with_cells<C2>(self, cells: C2) -> Ssd1306<DI, SIZE, TerminalMode<F, C2>>
+    where
+        C2: AsMut<[TerminalCell]>,
+    {
+        let (display, mode) = self.replace_mode(());
+        display.into_mode(mode.with_cells(cells))
+    }
+}
+
+impl<DI, SIZE, F, C> DisplayConfig for Ssd1306<DI, SIZE, TerminalMode<F, C>>
+where
+    DI: WriteOnlyDataCommand<Error = DisplayError>,
+    SIZE: TerminalDisplaySize,
+    F: TerminalFont,
+    C: AsMut<[TerminalCell]>,
 {
     type Error = TerminalModeError;
 
@@ -147,6 +494,7 @@ where
     ///
     /// This method resets the cursor but does not clear the screen.
     async fn set_rotation(&mut self, rot: DisplayRotation) -> Result<(), Self::Error> {
+        self.hide_cursor().await?;
         self.set_rotation(rot).await.terminal_err()?;
         // Need to reset cursor position, otherwise coordinates can become invalid
         self.reset_pos().await
@@ -155,87 +503,282 @@ where
     /// Initialise the display in page mode (i.e. a byte walks down a column of 8 pixels) with
     /// column 0 on the left and column _(SIZE::Width::U8 - 1)_ on the right, but no automatic line
     /// wrapping.
-    async fn init(&mut self) -> Result<(), Self::Error> {
+    async fn init_with_config(&mut self, config: InitConfig) -> Result<(), Self::Error> {
+        self.config = config;
         self.init_with_addr_mode(AddrMode::Page)
             .await
             .terminal_err()?;
+        self.mode.scroll = 0;
+        self.mode.parser = Parser::default();
+        self.mode.attributes = Attributes::new();
+        self.mode.saved_cursor = SavedCursor::default();
+        self.mode.cursor_drawn = None;
         self.reset_pos().await
     }
 }
 
-impl<DI, SIZE> Ssd1306<DI, SIZE, TerminalMode>
+impl<DI, SIZE, F, C> Ssd1306<DI, SIZE, TerminalMode<F, C>>
 where
     DI: WriteOnlyDataCommand<Error = DisplayError>,
     SIZE: TerminalDisplaySize,
+    F: TerminalFont,
+    C: AsMut<[TerminalCell]>,
 {
-    /// Clear the display and reset the cursor to the top left corner
-    pub async fn clear(&mut self) -> Result<(), TerminalModeError> {
-        // Let the chip handle line wrapping so we can fill the screen with blanks faster
-        self.set_addr_mode(AddrMode::Horizontal)
-            .await
-            .terminal_err()?;
-
-        let offset_x = match self.rotation() {
-            DisplayRotation::Rotate0 | DisplayRotation::Rotate270 => SIZE::OFFSETX,
-            DisplayRotation::Rotate180 | DisplayRotation::Rotate90 => {
-                // If segment remapping is flipped, we need to calculate
-                // the offset from the other edge of the display.
-                SIZE::DRIVER_COLS - SIZE::WIDTH - SIZE::OFFSETX
-            }
-        };
-        self.set_draw_area(
-            (offset_x, SIZE::OFFSETY),
-            (SIZE::WIDTH + offset_x, SIZE::HEIGHT + SIZE::OFFSETY),
-        )
-        .await
-        .terminal_err()?;
+    /// Stop hardware scrolling and redraw the text.
+    ///
+    /// Scrolling moves the contents of the display RAM, so it has to be rewritten after scrolling
+    /// has been stopped. The text is redrawn from the cells kept using
+    /// [`with_cells`](Self::with_cells), and cells which aren't kept are left empty. Without any
+    /// kept cells the display is cleared and the cursor moves to the top left corner.
+    pub async fn stop_scroll(&mut self) -> Result<(), TerminalModeError> {
+        self.disable_scroll().await.terminal_err()?;
 
-        // Clear the display
-        for _ in 0..SIZE::CHAR_NUM {
-            self.draw(&[0; 8]).await.terminal_err()?;
+        if self.mode.cells.is_empty() {
+            self.clear().await
+        } else {
+            self.redraw().await
         }
+    }
 
-        // But for normal operation we manage the line wrapping
-        self.set_addr_mode(AddrMode::Page).await.terminal_err()?;
+    /// Clear the display and reset the cursor to the top left corner
+    pub async fn clear(&mut self) -> Result<(), TerminalModeError> {
+        self.hide_cursor().await?;
+        self.erase_display().await?;
         self.reset_pos().await?;
 
         Ok(())
     }
 
-    /// Print a character to the display
+    /// Print a character to the display.
+    ///
+    /// Control characters and escape sequences are executed as described for [`TerminalMode`].
+    /// Escape sequences can be split across several calls.
     pub async fn print_char(&mut self, c: char) -> Result<(), TerminalModeError> {
+        self.hide_cursor().await?;
+
+        match self.mode.parser.advance(c) {
+            Some(Action::Print(c)) => {
+                self.draw_chars(c.encode_utf8(&mut [0; 4])).await?;
+
+                // Increment character counter and potentially wrap line
+                self.advance_cursor(1).await
+            }
+            Some(Action::Control(c)) => self.control(c).await,
+            Some(Action::Escape(c)) => self.escape(c).await,
+            Some(Action::Csi(csi)) => self.csi(csi).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Execute a control character
+    async fn control(&mut self, c: char) -> Result<(), TerminalModeError> {
+        let (column, row) = self.position()?;
+        let (width, _) = self.ensure_cursor()?.get_dimensions();
+
         match c {
             '\n' => {
-                let CursorWrapEvent(new_line) = self.ensure_cursor()?.advance_line();
-                self.set_column(0).await.terminal_err()?;
-                self.set_row(new_line * 8).await.terminal_err()?;
-            }
-            '\r' => {
-                self.set_column(0).await.terminal_err()?;
-                let (_, cur_line) = self.ensure_cursor()?.get_position();
-                self.ensure_cursor()?.set_position(0, cur_line);
-            }
-            _ => {
-                let bitmap = match self.rotation {
-                    DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
-                        Self::char_to_bitmap(c)
-                    }
-                    DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
-                        let bitmap = Self::char_to_bitmap(c);
-                        Self::rotate_bitmap(bitmap)
+                let scroll = self.scrolls();
+                let new_line = match self.ensure_cursor()?.advance_line(scroll) {
+                    CursorWrapEvent::Row(row) => row,
+                    CursorWrapEvent::Scroll => {
+                        self.scroll_line().await?;
+                        self.ensure_cursor()?.get_position().1
                     }
                 };
+                self.set_position(0, new_line).await?;
+            }
+            '\r' => self.set_position(0, row).await?,
+            '\t' => {
+                let tab_stop = (column / TAB_WIDTH + 1).saturating_mul(TAB_WIDTH);
+                self.set_position(min(tab_stop, width - 1), row).await?;
+            }
+            '\x08' => self.set_position(column.saturating_sub(1), row).await?,
+            '\x0c' => self.clear().await?,
+            _ => {}
+        }
 
-                self.draw(&bitmap).await.terminal_err()?;
+        Ok(())
+    }
 
-                // Increment character counter and potentially wrap line
-                self.advance_cursor().await?;
+    /// Execute an escape sequence which isn't a control sequence
+    async fn escape(&mut self, function: char) -> Result<(), TerminalModeError> {
+        match function {
+            '7' => self.save_cursor(),
+            '8' => self.restore_cursor().await,
+            _ => Ok(()),
+        }
+    }
+
+    /// Execute a control sequence
+    async fn csi(&mut self, csi: Csi) -> Result<(), TerminalModeError> {
+        let (column, row) = self.position()?;
+        let (width, height) = self.ensure_cursor()?.get_dimensions();
+        // Clamp a parameter to the character coordinates
+        let clamp = |value: u16, limit: u8| min(value, limit as u16 - 1) as u8;
+
+        match csi.function {
+            'A' => {
+                let row = (row as u16).saturating_sub(csi.count(0));
+                self.set_position(column, row as u8).await
+            }
+            'B' => {
+                let row = clamp(row as u16 + csi.count(0), height);
+                self.set_position(column, row).await
+            }
+            'C' => {
+                let column = clamp(column as u16 + csi.count(0), width);
+                self.set_position(column, row).await
+            }
+            'D' => {
+                let column = (column as u16).saturating_sub(csi.count(0));
+                self.set_position(column as u8, row).await
+            }
+            'H' | 'f' => {
+                let row = clamp(csi.count(0) - 1, height);
+                let column = clamp(csi.count(1) - 1, width);
+                self.set_position(column, row).await
+            }
+            'J' => match csi.param(0) {
+                0 => {
+                    self.clear_to_end_of_line().await?;
+                    self.clear_region(0, row + 1, width, height - row - 1).await
+                }
+                1 => {
+                    self.clear_region(0, 0, width, row).await?;
+                    self.clear_region(0, row, column + 1, 1).await
+                }
+                2 => {
+                    self.erase_display().await?;
+                    self.set_position(column, row).await
+                }
+                _ => Ok(()),
+            },
+            'K' => match csi.param(0) {
+                0 => self.clear_to_end_of_line().await,
+                1 => self.clear_region(0, row, column + 1, 1).await,
+                2 => self.clear_line(row).await,
+                _ => Ok(()),
+            },
+            'm' => {
+                let attributes = &mut self.mode.attributes;
+                for &param in csi.params() {
+                    *attributes = match param {
+                        0 => Attributes::new(),
+                        4 | 24 => attributes.underline(param == 4),
+                        7 | 27 => attributes.inverse(param == 7),
+                        9 | 29 => attributes.strikethrough(param == 9),
+                        // The parameters of extended colors could be mistaken for attributes
+                        38 | 48 | 58 => break,
+                        _ => *attributes,
+                    };
+                }
+                Ok(())
+            }
+            's' => self.save_cursor(),
+            'u' => self.restore_cursor().await,
+            _ => Ok(()),
+        }
+    }
+
+    /// Save the cursor position and attributes
+    fn save_cursor(&mut self) -> Result<(), TerminalModeError> {
+        self.mode.saved_cursor = SavedCursor {
+            position: self.position()?,
+            attributes: self.mode.attributes,
+        };
+        Ok(())
+    }
+
+    /// Restore the cursor position and attributes saved by [`save_cursor`](Self::save_cursor)
+    async fn restore_cursor(&mut self) -> Result<(), TerminalModeError> {
+        let SavedCursor {
+            position,
+            attributes,
+        } = self.mode.saved_cursor;
+        let (width, height) = self.ensure_cursor()?.get_dimensions();
+
+        self.mode.attributes = attributes;
+        self.set_position(min(position.0, width - 1), min(position.1, height - 1))
+            .await
+    }
+
+    /// Print a string to the display.
+    ///
+    /// Consecutive characters on the same line are sent to the display in a single transfer.
+    pub async fn write_str(&mut self, s: &str) -> Result<(), TerminalModeError> {
+        self.hide_cursor().await?;
+        let mut rest = s;
+
+        while let Some(first) = rest.chars().next() {
+            // Only characters on the same line are next to each other in the display RAM
+            let (col, _) = self.position()?;
+            let (width, _) = self.ensure_cursor()?.get_dimensions();
+            let run_len = match self.rotation {
+                DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => width - col,
+                DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => 1,
+            };
+
+            // Control characters and escape sequences are handled by `print_char`
+            let ground = self.mode.parser.is_ground();
+            let mut chars = 0;
+            let mut len = 0;
+            for c in rest.chars() {
+                if chars == run_len || !ground || c.is_control() {
+                    break;
+                }
+                chars += 1;
+                len += c.len_utf8();
+            }
+
+            if chars == 0 {
+                self.print_char(first).await?;
+                rest = &rest[first.len_utf8()..];
+            } else {
+                self.draw_chars(&rest[..len]).await?;
+                self.advance_cursor(chars).await?;
+                rest = &rest[len..];
             }
         }
 
         Ok(())
     }
 
+    /// Print formatted text to the display.
+    ///
+    /// This allows using `write!(display, "{}", value).await`.
+    ///
+    /// The text is printed in chunks of up to 32 bytes, formatting the arguments again for every
+    /// chunk. `Display` implementations therefore run more than once for longer text, and should
+    /// give the same output every time.
+    pub async fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> Result<(), TerminalModeError> {
+        // Formatting can't be suspended while the text is sent to the display, so the arguments
+        // are formatted again for every chunk, skipping the text that has already been printed.
+        let mut printed = 0;
+
+        loop {
+            let mut chunk = FmtChunk {
+                skip: printed,
+                buf: [0; FMT_CHUNK_LEN],
+                len: 0,
+                full: false,
+            };
+            let result = fmt::write(&mut chunk, args);
+            if result.is_err() && !chunk.full {
+                return Err(TerminalModeError::FormatError);
+            }
+
+            // The chunk only contains complete characters
+            let text = core::str::from_utf8(&chunk.buf[..chunk.len])
+                .map_err(|_| TerminalModeError::FormatError)?;
+            self.write_str(text).await?;
+            printed += chunk.len;
+
+            if !chunk.full {
+                return Ok(());
+            }
+        }
+    }
+
     /// Get the current cursor position, in character coordinates.
     /// This is the (column, row) that the next character will be written to.
     pub fn position(&self) -> Result<(u8, u8), TerminalModeError> {
@@ -254,41 +797,187 @@ where
         if column >= width || row >= height {
             Err(TerminalModeError::OutOfBounds)
         } else {
-            let offset_x = match self.rotation() {
-                DisplayRotation::Rotate0 | DisplayRotation::Rotate270 => SIZE::OFFSETX,
-                DisplayRotation::Rotate180 | DisplayRotation::Rotate90 => {
-                    // If segment remapping is flipped, we need to calculate
-                    // the offset from the other edge of the display.
-                    SIZE::DRIVER_COLS - SIZE::WIDTH - SIZE::OFFSETX
-                }
-            };
-            match self.rotation() {
-                DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
-                    self.set_column(offset_x + column * 8)
-                        .await
-                        .terminal_err()?;
-                    self.set_row(SIZE::OFFSETY + row * 8).await.terminal_err()?;
-                }
-                DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
-                    self.set_column(offset_x + row * 8).await.terminal_err()?;
-                    self.set_row(SIZE::OFFSETY + column * 8)
-                        .await
-                        .terminal_err()?;
-                }
-            }
+            self.hide_cursor().await?;
+            self.point_to(column, row).await?;
             self.ensure_cursor()?.set_position(column, row);
             Ok(())
         }
     }
 
+    /// Set how the cursor is shown. The cursor is shown at [`position`](Self::position) right
+    /// away unless `style` is [`CursorStyle::Hidden`].
+    ///
+    /// The cursor is removed while printing or moving it, and shown again by the next call to
+    /// [`blink_cursor_tick`](Self::blink_cursor_tick).
+    ///
+    /// Showing the cursor needs the contents of the cells, so
+    /// [`TerminalModeError::NoCellStorage`] is returned unless they are kept using
+    /// [`with_cells`](Self::with_cells).
+    pub async fn set_cursor_style(&mut self, style: CursorStyle) -> Result<(), TerminalModeError> {
+        if style != CursorStyle::Hidden && self.mode.cells.is_empty() {
+            return Err(TerminalModeError::NoCellStorage);
+        }
+
+        self.hide_cursor().await?;
+        self.mode.cursor_style = style;
+        self.show_cursor().await
+    }
+
+    /// Get how the cursor is shown.
+    pub fn cursor_style(&self) -> CursorStyle {
+        self.mode.cursor_style
+    }
+
+    /// Show the cursor if it's not shown, or remove it otherwise.
+    ///
+    /// Call this periodically, e.g. every 500ms, to blink the cursor. The display RAM can't be
+    /// read, so the character under the cursor is redrawn from the cells kept using
+    /// [`with_cells`](Self::with_cells). The cursor isn't shown over cells which aren't kept.
+    pub async fn blink_cursor_tick(&mut self) -> Result<(), TerminalModeError> {
+        if self.mode.cursor_drawn.is_some() {
+            self.hide_cursor().await
+        } else {
+            self.show_cursor().await
+        }
+    }
+
+    /// Set the attributes used to draw the following characters.
+    pub fn set_attributes(&mut self, attributes: Attributes) {
+        self.mode.attributes = attributes;
+    }
+
+    /// Get the attributes used to draw the following characters.
+    pub fn attributes(&self) -> Attributes {
+        self.mode.attributes
+    }
+
+    /// Print a string to the display using the given attributes, e.g. to highlight a menu entry.
+    ///
+    /// The previous attributes are restored afterwards, even if the string changes them using
+    /// escape sequences.
+    pub async fn print_str_with(
+        &mut self,
+        attributes: Attributes,
+        s: &str,
+    ) -> Result<(), TerminalModeError> {
+        let previous = core::mem::replace(&mut self.mode.attributes, attributes);
+        let result = self.write_str(s).await;
+        self.mode.attributes = previous;
+        result
+    }
+
+    /// Clear a line of text, without moving the cursor.
+    pub async fn clear_line(&mut self, row: u8) -> Result<(), TerminalModeError> {
+        let (width, _) = self.ensure_cursor()?.get_dimensions();
+        self.clear_region(0, row, width, 1).await
+    }
+
+    /// Clear the current line from the cursor to its end, without moving the cursor.
+    ///
+    /// This removes any stale characters left over when a shorter text is printed over a line.
+    pub async fn clear_to_end_of_line(&mut self) -> Result<(), TerminalModeError> {
+        let (column, row) = self.position()?;
+        let (width, _) = self.ensure_cursor()?.get_dimensions();
+        self.clear_region(column, row, width - column, 1).await
+    }
+
+    /// Clear a rectangle of `width` x `height` characters with its top left corner at (`column`,
+    /// `row`), without moving the cursor.
+    /// If the rectangle doesn't fit on the display, an Err will be returned.
+    pub async fn clear_region(
+        &mut self,
+        column: u8,
+        row: u8,
+        width: u8,
+        height: u8,
+    ) -> Result<(), TerminalModeError> {
+        self.fill_region(column, row, width, height, 0).await
+    }
+
+    /// Fill a rectangle of `width` x `height` characters with its top left corner at (`column`,
+    /// `row`) with a byte, without moving the cursor.
+    ///
+    /// The byte is written to every column of the character cells in the display RAM, so e.g.
+    /// `0xFF` sets all pixels of the cells.
+    /// If the rectangle doesn't fit on the display, an Err will be returned.
+    pub async fn fill_region(
+        &mut self,
+        column: u8,
+        row: u8,
+        width: u8,
+        height: u8,
+        byte: u8,
+    ) -> Result<(), TerminalModeError> {
+        let (position_col, position_row) = self.position()?;
+        let (max_width, max_height) = self.ensure_cursor()?.get_dimensions();
+        if column as u16 + width as u16 > max_width as u16
+            || row as u16 + height as u16 > max_height as u16
+        {
+            return Err(TerminalModeError::OutOfBounds);
+        }
+
+        self.hide_cursor().await?;
+        for row in row..row + height {
+            self.fill_cells(row, column..column + width, byte).await?;
+            for column in column..column + width {
+                self.mode.cells.set(column, row, Cell::fill(byte));
+            }
+        }
+
+        // Filling moved the display RAM pointer away from the cursor
+        self.set_position(position_col, position_row).await
+    }
+
+    /// Set what happens when the cursor moves past the last line of the display.
+    pub fn set_overflow(&mut self, overflow: Overflow) {
+        self.mode.overflow = overflow;
+    }
+
+    /// Get what happens when the cursor moves past the last line of the display.
+    pub fn overflow(&self) -> Overflow {
+        self.mode.overflow
+    }
+
+    /// Set the scale of the characters, e.g. to show a large readout.
+    ///
+    /// The display is divided into cells of the scaled character size, so this moves the cursor to
+    /// the top left corner and changes [`char_dimensions`](Self::char_dimensions). Text which is
+    /// already shown is kept. If a scaled character doesn't fit on the display, an Err will be
+    /// returned.
+    pub async fn set_text_scale(&mut self, scale: TextScale) -> Result<(), TerminalModeError> {
+        let previous = core::mem::replace(&mut self.mode.text_scale, scale);
+        let (width, height) = self.char_dimensions();
+        self.mode.text_scale = previous;
+        if width == 0 || height == 0 {
+            return Err(TerminalModeError::OutOfBounds);
+        }
+
+        // The cursor has to be removed from the cell of the previous size
+        self.hide_cursor().await?;
+        self.mode.text_scale = scale;
+
+        if self.mode.cursor.is_some() {
+            self.mode.cursor = Some(Cursor::new(width, height));
+            self.mode.cells.reset(width);
+            self.set_position(0, 0).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the scale of the characters.
+    pub fn text_scale(&self) -> TextScale {
+        self.mode.text_scale
+    }
+
     /// Reset the draw area and move pointer to the top left corner
     async fn reset_pos(&mut self) -> Result<(), TerminalModeError> {
+        self.reset_scroll().await?;
+
         // Initialise the counter when we know it's valid
-        let (w, h) = match self.rotation() {
-            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (SIZE::WIDTH, SIZE::HEIGHT),
-            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (SIZE::HEIGHT, SIZE::WIDTH),
-        };
+        let (w, h) = self.char_dimensions();
         self.mode.cursor = Some(Cursor::new(w, h));
+        self.mode.cells.reset(w);
 
         // Reset cursor position
         self.set_position(0, 0).await?;
@@ -296,18 +985,197 @@ where
         Ok(())
     }
 
-    /// Advance the cursor, automatically wrapping lines and/or screens if necessary
-    /// Takes in an already-unwrapped cursor to avoid re-unwrapping
-    async fn advance_cursor(&mut self) -> Result<(), TerminalModeError> {
-        let cursor = self.ensure_cursor()?;
+    /// Advance the cursor by `chars` characters, automatically wrapping lines and/or screens if
+    /// necessary
+    async fn advance_cursor(&mut self, chars: u8) -> Result<(), TerminalModeError> {
+        let scroll = self.scrolls();
 
-        cursor.advance();
-        let (c, r) = cursor.get_position();
+        for _ in 0..chars {
+            if let Some(CursorWrapEvent::Scroll) = self.ensure_cursor()?.advance(scroll) {
+                self.scroll_line().await?;
+            }
+        }
+        let (c, r) = self.ensure_cursor()?.get_position();
         self.set_position(c, r).await?;
 
         Ok(())
     }
 
+    /// Whether the text scrolls when the cursor moves past the last line
+    fn scrolls(&self) -> bool {
+        self.mode.overflow == Overflow::Scroll
+            && matches!(
+                self.rotation,
+                DisplayRotation::Rotate0 | DisplayRotation::Rotate180
+            )
+    }
+
+    /// Scroll the text up by a line by moving the display start line, and clear the new last line
+    async fn scroll_line(&mut self) -> Result<(), TerminalModeError> {
+        let (_, cell_pages) = self.cell_size();
+        let (_, rows) = self.ensure_cursor()?.get_dimensions();
+
+        self.mode.scroll = (self.mode.scroll + cell_pages * 8) % SIZE::DRIVER_ROWS;
+        Command::StartLine(self.config.start_line + SIZE::OFFSETY + self.mode.scroll)
+            .send(&mut self.interface)
+            .await
+            .terminal_err()?;
+        self.mode.cells.scroll(rows);
+
+        // Clear the last line, along with any rows below it which don't fit a whole line
+        let (x, y) = self.cell_origin(0, rows - 1);
+        let pages = SIZE::HEIGHT.div_ceil(8) - (rows - 1) * cell_pages;
+        for page in 0..pages {
+            let row = (y + page * 8) % SIZE::DRIVER_ROWS;
+            self.fill_area((x, row), (x + SIZE::WIDTH, row + 8), 0)
+                .await
+                .terminal_err()?;
+        }
+
+        Ok(())
+    }
+
+    /// Draw the whole display again from the kept cells, without moving the cursor
+    async fn redraw(&mut self) -> Result<(), TerminalModeError> {
+        // The cursor is erased along with the display RAM
+        self.mode.cursor_drawn = None;
+        self.reset_scroll().await?;
+        self.fill_display(0).await.terminal_err()?;
+
+        let (width, height) = self.ensure_cursor()?.get_dimensions();
+        for row in 0..height {
+            for column in 0..width {
+                match self.mode.cells.get(column, row) {
+                    Some(cell) if cell != Cell::BLANK => self.draw_cell(column, row, cell).await?,
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Clear the whole display without moving the cursor
+    async fn erase_display(&mut self) -> Result<(), TerminalModeError> {
+        self.reset_scroll().await?;
+        let (width, _) = self.char_dimensions();
+        self.mode.cells.reset(width);
+        self.fill_display(0).await.terminal_err()
+    }
+
+    /// Fill the given character cells of a row with a byte.
+    ///
+    /// This moves the display RAM pointer, so the cursor position has to be set again afterwards.
+    async fn fill_cells(
+        &mut self,
+        row: u8,
+        columns: Range<u8>,
+        byte: u8,
+    ) -> Result<(), TerminalModeError> {
+        if columns.is_empty() {
+            return Ok(());
+        }
+
+        let (cell_cols, cell_pages) = self.cell_size();
+        // Only the cells of unrotated rows are next to each other in the display RAM
+        let run = match self.rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => columns.len() as u8,
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => 1,
+        };
+
+        for column in columns.step_by(run as usize) {
+            let (x, y) = self.cell_origin(column, row);
+            for page in 0..cell_pages {
+                let y = (y + page * 8) % SIZE::DRIVER_ROWS;
+                self.fill_area((x, y), (x + run * cell_cols, y + 8), byte)
+                    .await
+                    .terminal_err()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Draw the cursor over the cell at the cursor position, unless it's hidden
+    async fn show_cursor(&mut self) -> Result<(), TerminalModeError> {
+        let (column, row) = self.position()?;
+        let Some(cell) = self.mode.cells.get(column, row) else {
+            return Ok(());
+        };
+        let cell = match (self.mode.cursor_style, cell) {
+            (CursorStyle::Hidden, _) => return Ok(()),
+            (CursorStyle::Block, Cell::Char(c, attributes)) => Cell::Char(
+                c,
+                attributes.with(Attributes::INVERSE, !attributes.has(Attributes::INVERSE)),
+            ),
+            (CursorStyle::Underline, Cell::Char(c, attributes)) => Cell::Char(
+                c,
+                attributes.with(
+                    Attributes::UNDERLINE,
+                    !attributes.has(Attributes::UNDERLINE),
+                ),
+            ),
+            // There's no bottom row in filled cells
+            (_, Cell::Fill(byte)) => Cell::Fill(!byte),
+        };
+
+        self.draw_cell(column, row, cell).await?;
+        self.mode.cursor_drawn = Some((column, row));
+        Ok(())
+    }
+
+    /// Remove the cursor by drawing the contents of the cell below it again
+    async fn hide_cursor(&mut self) -> Result<(), TerminalModeError> {
+        if let Some((column, row)) = self.mode.cursor_drawn.take() {
+            if let Some(cell) = self.mode.cells.get(column, row) {
+                self.draw_cell(column, row, cell).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Draw a cell without storing its contents, and move the display RAM pointer back to the
+    /// cursor afterwards
+    async fn draw_cell(
+        &mut self,
+        column: u8,
+        row: u8,
+        cell: Cell,
+    ) -> Result<(), TerminalModeError> {
+        match cell {
+            Cell::Char(c, attributes) => {
+                self.point_to(column, row).await?;
+                self.draw_glyphs(column, row, c.encode_utf8(&mut [0; 4]), attributes)
+                    .await?;
+            }
+            Cell::Fill(byte) => self.fill_cells(row, column..column + 1, byte).await?,
+        }
+
+        let (column, row) = self.position()?;
+        self.point_to(column, row).await
+    }
+
+    /// Move the display RAM pointer to a character cell
+    async fn point_to(&mut self, column: u8, row: u8) -> Result<(), TerminalModeError> {
+        let (x, y) = self.cell_origin(column, row);
+        self.set_column(x).await.terminal_err()?;
+        self.set_row(y).await.terminal_err()
+    }
+
+    /// Move the display start line back to show the display RAM without scrolling
+    async fn reset_scroll(&mut self) -> Result<(), TerminalModeError> {
+        if self.mode.scroll != 0 {
+            self.mode.scroll = 0;
+            Command::StartLine(self.config.start_line + SIZE::OFFSETY)
+                .send(&mut self.interface)
+                .await
+                .terminal_err()?;
+        }
+
+        Ok(())
+    }
+
     fn ensure_cursor(&mut self) -> Result<&mut Cursor, TerminalModeError> {
         self.mode
             .cursor
@@ -315,133 +1183,288 @@ where
             .ok_or(TerminalModeError::Uninitialized)
     }
 
-    fn char_to_bitmap(input: char) -> [u8; 8] {
-        // Populate the array with the data from the character array at the right index
-        match input {
-            '!' => [0x00, 0x00, 0x2f, 0x00, 0x00, 0x00, 0x00, 0x00],
-            '"' => [0x00, 0x03, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00],
-            '#' => [0x00, 0x12, 0x3f, 0x12, 0x12, 0x3f, 0x12, 0x00],
-            '$' => [0x00, 0x2e, 0x2a, 0x7f, 0x2a, 0x3a, 0x00, 0x00],
-            '%' => [0x00, 0x23, 0x13, 0x08, 0x04, 0x32, 0x31, 0x00],
-            '&' => [0x00, 0x10, 0x2a, 0x25, 0x2a, 0x10, 0x20, 0x00],
-            '\'' => [0x00, 0x02, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00],
-            '(' => [0x00, 0x1e, 0x21, 0x00, 0x00, 0x00, 0x00, 0x00],
-            ')' => [0x00, 0x21, 0x1e, 0x00, 0x00, 0x00, 0x00, 0x00],
-            '*' => [0x00, 0x08, 0x2a, 0x1c, 0x2a, 0x08, 0x00, 0x00],
-            '+' => [0x00, 0x08, 0x08, 0x3e, 0x08, 0x08, 0x00, 0x00],
-            ',' => [0x00, 0x80, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00],
-            '-' => [0x00, 0x08, 0x08, 0x08, 0x08, 0x08, 0x00, 0x00],
-            '.' => [0x00, 0x30, 0x30, 0x00, 0x00, 0x00, 0x00, 0x00],
-            '/' => [0x00, 0x20, 0x10, 0x08, 0x04, 0x02, 0x00, 0x00],
-            '0' => [0x00, 0x1e, 0x31, 0x29, 0x25, 0x23, 0x1e, 0x00],
-            '1' => [0x00, 0x22, 0x21, 0x3f, 0x20, 0x20, 0x20, 0x00],
-            '2' => [0x00, 0x32, 0x29, 0x29, 0x29, 0x29, 0x26, 0x00],
-            '3' => [0x00, 0x12, 0x21, 0x21, 0x25, 0x25, 0x1a, 0x00],
-            '4' => [0x00, 0x18, 0x14, 0x12, 0x3f, 0x10, 0x00, 0x00],
-            '5' => [0x00, 0x17, 0x25, 0x25, 0x25, 0x25, 0x19, 0x00],
-            '6' => [0x00, 0x1e, 0x25, 0x25, 0x25, 0x25, 0x18, 0x00],
-            '7' => [0x00, 0x01, 0x01, 0x31, 0x09, 0x05, 0x03, 0x00],
-            '8' => [0x00, 0x1a, 0x25, 0x25, 0x25, 0x25, 0x1a, 0x00],
-            '9' => [0x00, 0x06, 0x29, 0x29, 0x29, 0x29, 0x1e, 0x00],
-            ':' => [0x00, 0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
-            ';' => [0x00, 0x80, 0x64, 0x00, 0x00, 0x00, 0x00, 0x00],
-            '<' => [0x00, 0x08, 0x14, 0x22, 0x00, 0x00, 0x00, 0x00],
-            '=' => [0x00, 0x14, 0x14, 0x14, 0x14, 0x14, 0x00, 0x00],
-            '>' => [0x00, 0x22, 0x14, 0x08, 0x00, 0x00, 0x00, 0x00],
-            '?' => [0x00, 0x02, 0x01, 0x01, 0x29, 0x05, 0x02, 0x00],
-            '@' => [0x00, 0x1e, 0x21, 0x2d, 0x2b, 0x2d, 0x0e, 0x00],
-            'A' => [0x00, 0x3e, 0x09, 0x09, 0x09, 0x09, 0x3e, 0x00],
-            'B' => [0x00, 0x3f, 0x25, 0x25, 0x25, 0x25, 0x1a, 0x00],
-            'C' => [0x00, 0x1e, 0x21, 0x21, 0x21, 0x21, 0x12, 0x00],
-            'D' => [0x00, 0x3f, 0x21, 0x21, 0x21, 0x12, 0x0c, 0x00],
-            'E' => [0x00, 0x3f, 0x25, 0x25, 0x25, 0x25, 0x21, 0x00],
-            'F' => [0x00, 0x3f, 0x05, 0x05, 0x05, 0x05, 0x01, 0x00],
-            'G' => [0x00, 0x1e, 0x21, 0x21, 0x21, 0x29, 0x1a, 0x00],
-            'H' => [0x00, 0x3f, 0x04, 0x04, 0x04, 0x04, 0x3f, 0x00],
-            'I' => [0x00, 0x21, 0x21, 0x3f, 0x21, 0x21, 0x00, 0x00],
-            'J' => [0x00, 0x10, 0x20, 0x20, 0x20, 0x20, 0x1f, 0x00],
-            'K' => [0x00, 0x3f, 0x04, 0x0c, 0x0a, 0x11, 0x20, 0x00],
-            'L' => [0x00, 0x3f, 0x20, 0x20, 0x20, 0x20, 0x20, 0x00],
-            'M' => [0x00, 0x3f, 0x02, 0x04, 0x04, 0x02, 0x3f, 0x00],
-            'N' => [0x00, 0x3f, 0x02, 0x04, 0x08, 0x10, 0x3f, 0x00],
-            'O' => [0x00, 0x1e, 0x21, 0x21, 0x21, 0x21, 0x1e, 0x00],
-            'P' => [0x00, 0x3f, 0x09, 0x09, 0x09, 0x09, 0x06, 0x00],
-            'Q' => [0x00, 0x1e, 0x21, 0x29, 0x31, 0x21, 0x5e, 0x00],
-            'R' => [0x00, 0x3f, 0x09, 0x09, 0x09, 0x19, 0x26, 0x00],
-            'S' => [0x00, 0x12, 0x25, 0x25, 0x25, 0x25, 0x18, 0x00],
-            'T' => [0x00, 0x01, 0x01, 0x01, 0x3f, 0x01, 0x01, 0x00],
-            'U' => [0x00, 0x1f, 0x20, 0x20, 0x20, 0x20, 0x1f, 0x00],
-            'V' => [0x00, 0x0f, 0x10, 0x20, 0x20, 0x10, 0x0f, 0x00],
-            'W' => [0x00, 0x1f, 0x20, 0x10, 0x10, 0x20, 0x1f, 0x00],
-            'X' => [0x00, 0x21, 0x12, 0x0c, 0x0c, 0x12, 0x21, 0x00],
-            'Y' => [0x00, 0x01, 0x02, 0x3c, 0x02, 0x01, 0x00, 0x00],
-            'Z' => [0x00, 0x21, 0x31, 0x29, 0x25, 0x23, 0x21, 0x00],
-            '[' => [0x00, 0x3f, 0x21, 0x00, 0x00, 0x00, 0x00, 0x00],
-            '\\' => [0x00, 0x02, 0x04, 0x08, 0x10, 0x20, 0x00, 0x00],
-            ']' => [0x00, 0x21, 0x3f, 0x00, 0x00, 0x00, 0x00, 0x00],
-            '^' => [0x00, 0x04, 0x02, 0x3f, 0x02, 0x04, 0x00, 0x00],
-            '_' => [0x00, 0x40, 0x40, 0x40, 0x40, 0x40, 0x40, 0x00],
-            '`' => [0x00, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00],
-            'a' => [0x00, 0x10, 0x2a, 0x2a, 0x2a, 0x3c, 0x00, 0x00],
-            'b' => [0x00, 0x3f, 0x24, 0x24, 0x24, 0x18, 0x00, 0x00],
-            'c' => [0x00, 0x1c, 0x22, 0x22, 0x22, 0x00, 0x00, 0x00],
-            'd' => [0x00, 0x18, 0x24, 0x24, 0x24, 0x3f, 0x00, 0x00],
-            'e' => [0x00, 0x1c, 0x2a, 0x2a, 0x2a, 0x24, 0x00, 0x00],
-            'f' => [0x00, 0x00, 0x3e, 0x05, 0x01, 0x00, 0x00, 0x00],
-            'g' => [0x00, 0x18, 0xa4, 0xa4, 0xa4, 0x7c, 0x00, 0x00],
-            'h' => [0x00, 0x3f, 0x04, 0x04, 0x04, 0x38, 0x00, 0x00],
-            'i' => [0x00, 0x00, 0x24, 0x3d, 0x20, 0x00, 0x00, 0x00],
-            'j' => [0x00, 0x20, 0x40, 0x40, 0x3d, 0x00, 0x00, 0x00],
-            'k' => [0x00, 0x3f, 0x0c, 0x12, 0x20, 0x00, 0x00, 0x00],
-            'l' => [0x00, 0x1f, 0x20, 0x20, 0x00, 0x00, 0x00, 0x00],
-            'm' => [0x00, 0x3e, 0x02, 0x3c, 0x02, 0x3c, 0x00, 0x00],
-            'n' => [0x00, 0x3e, 0x02, 0x02, 0x02, 0x3c, 0x00, 0x00],
-            'o' => [0x00, 0x1c, 0x22, 0x22, 0x22, 0x1c, 0x00, 0x00],
-            'p' => [0x00, 0xfc, 0x24, 0x24, 0x24, 0x18, 0x00, 0x00],
-            'q' => [0x00, 0x18, 0x24, 0x24, 0x24, 0xfc, 0x00, 0x00],
-            'r' => [0x00, 0x3e, 0x04, 0x02, 0x02, 0x00, 0x00, 0x00],
-            's' => [0x00, 0x24, 0x2a, 0x2a, 0x2a, 0x10, 0x00, 0x00],
-            't' => [0x00, 0x02, 0x1f, 0x22, 0x20, 0x00, 0x00, 0x00],
-            'u' => [0x00, 0x1e, 0x20, 0x20, 0x20, 0x1e, 0x00, 0x00],
-            'v' => [0x00, 0x06, 0x18, 0x20, 0x18, 0x06, 0x00, 0x00],
-            'w' => [0x00, 0x1e, 0x30, 0x1c, 0x30, 0x1e, 0x00, 0x00],
-            'x' => [0x00, 0x22, 0x14, 0x08, 0x14, 0x22, 0x00, 0x00],
-            'y' => [0x00, 0x1c, 0xa0, 0xa0, 0xa0, 0x7c, 0x00, 0x00],
-            'z' => [0x00, 0x22, 0x32, 0x2a, 0x26, 0x22, 0x00, 0x00],
-            '{' => [0x00, 0x0c, 0x3f, 0x21, 0x00, 0x00, 0x00, 0x00],
-            '|' => [0x00, 0x3f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
-            '}' => [0x00, 0x21, 0x3f, 0x0c, 0x00, 0x00, 0x00, 0x00],
-            '~' => [0x00, 0x02, 0x01, 0x02, 0x01, 0x00, 0x00, 0x00],
-            _ => [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
-        }
-    }
-
-    fn rotate_bitmap(bitmap: [u8; 8]) -> [u8; 8] {
-        let mut rotated: [u8; 8] = [0; 8];
-
-        for col in 0..8 {
-            // source.msb is the top pixel
-            let source = bitmap[col];
-            for row in 0..8 {
-                let bit = source & 1 << row != 0;
-                if bit {
-                    rotated[row] |= 1 << col;
-                }
+    /// Get the number of characters that fit on the display with the current font, text scale and
+    /// rotation, as (columns, rows).
+    pub fn char_dimensions(&self) -> (u8, u8) {
+        let (cell_cols, cell_pages) = self.cell_size();
+        match self.rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
+                (SIZE::WIDTH / cell_cols, SIZE::HEIGHT / (cell_pages * 8))
+            }
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
+                (SIZE::HEIGHT / (cell_pages * 8), SIZE::WIDTH / cell_cols)
+            }
+        }
+    }
+
+    /// Size of a character cell in the display RAM, as (columns, pages).
+    ///
+    /// Rotated characters are padded to whole pages, as the display RAM is written a page at a
+    /// time.
+    fn cell_size(&self) -> (u8, u8) {
+        let (scale_x, scale_y) = self.mode.text_scale.factors();
+        match self.rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => {
+                (F::WIDTH * scale_x, F::PAGES * scale_y)
+            }
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
+                (F::PAGES * 8 * scale_y, (F::WIDTH * scale_x).div_ceil(8))
+            }
+        }
+    }
+
+    /// Position of the top left corner of a character cell in the display RAM, in pixels
+    fn cell_origin(&self, column: u8, row: u8) -> (u8, u8) {
+        let (cell_cols, cell_pages) = self.cell_size();
+        let (x, y) = match self.rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (column, row),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (row, column),
+        };
+
+        (
+            self.column_offset() + x * cell_cols,
+            (SIZE::OFFSETY + y * cell_pages * 8 + self.mode.scroll) % SIZE::DRIVER_ROWS,
+        )
+    }
+
+    /// Draw characters starting at the cursor, without moving the cursor.
+    ///
+    /// The characters must all fit into the display RAM page(s) of the current line.
+    async fn draw_chars(&mut self, s: &str) -> Result<(), TerminalModeError> {
+        let (column, row) = self.position()?;
+        let attributes = self.mode.attributes;
+        for (i, c) in s.chars().enumerate() {
+            self.mode
+                .cells
+                .set(column + i as u8, row, Cell::Char(c, attributes));
+        }
+
+        self.draw_glyphs(column, row, s, attributes).await
+    }
+
+    /// Draw characters starting at a cell, which the display RAM pointer must point to.
+    ///
+    /// The characters must all fit into the display RAM page(s) of the line.
+    async fn draw_glyphs(
+        &mut self,
+        column: u8,
+        row: u8,
+        s: &str,
+        attributes: Attributes,
+    ) -> Result<(), TerminalModeError> {
+        let (x, y) = self.cell_origin(column, row);
+        let (cell_cols, cell_pages) = self.cell_size();
+        let rotation = self.rotation;
+        let scale = self.mode.text_scale.factors();
+
+        for page in 0..cell_pages {
+            // The first page of a cell is already selected by the cursor
+            if page > 0 {
+                self.set_column(x).await.terminal_err()?;
+                self.set_row((y + page * 8) % SIZE::DRIVER_ROWS)
+                    .await
+                    .terminal_err()?;
+            }
+
+            let font = &self.mode.font;
+            let mut bytes = s.chars().flat_map(|c| {
+                let glyph = glyph_or_fallback(font, c);
+                (0..cell_cols)
+                    .map(move |col| Self::cell_byte(glyph, attributes, rotation, scale, col, page))
+            });
+            self.interface
+                .send_data(DataFormat::U8Iter(&mut bytes))
+                .await
+                .terminal_err()?;
+        }
+
+        if cell_pages > 1 {
+            self.set_column(x).await.terminal_err()?;
+            self.set_row(y).await.terminal_err()?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the byte at `col` in `page` of a character cell in the display RAM
+    fn cell_byte(
+        glyph: &[u8],
+        attributes: Attributes,
+        rotation: DisplayRotation,
+        (scale_x, scale_y): (u8, u8),
+        col: u8,
+        page: u8,
+    ) -> u8 {
+        // Lines are drawn onto the glyph before rotating and scaling it, so they stay horizontal
+        let byte = |col: u8, page: u8| attributes.glyph_byte::<F>(glyph, col, page);
+        // Get a pixel of the scaled glyph
+        let pixel = |x: u8, y: u8| {
+            let (x, y) = (x / scale_x, y / scale_y);
+            byte(x, y / 8) & 1 << (y % 8) != 0
+        };
+
+        let byte = match rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 if scale_y == 1 => {
+                byte(col / scale_x, page)
+            }
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (0..8)
+                .fold(0, |scaled, bit| {
+                    scaled | (pixel(col, page * 8 + bit) as u8) << bit
+                }),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
+                // Columns of the cell are rows of the glyph and vice versa
+                (0..8)
+                    .map(|bit| page * 8 + bit)
+                    .filter(|&x| x < F::WIDTH * scale_x)
+                    .enumerate()
+                    .fold(0, |rotated, (bit, x)| {
+                        rotated | (pixel(x, col) as u8) << bit
+                    })
+            }
+        };
+
+        byte ^ attributes.invert_mask()
+    }
+}
+
+#[cfg(feature = "io")]
+impl<DI, SIZE, F, C> ErrorType for Ssd1306<DI, SIZE, TerminalMode<F, C>>
+where
+    DI: WriteOnlyDataCommand<Error = DisplayError>,
+    SIZE: TerminalDisplaySize,
+    F: TerminalFont,
+    C: AsMut<[TerminalCell]>,
+{
+    type Error = TerminalModeError;
+}
+
+#[cfg(feature = "io")]
+impl<DI, SIZE, F, C> Write for Ssd1306<DI, SIZE, TerminalMode<F, C>>
+where
+    DI: WriteOnlyDataCommand<Error = DisplayError>,
+    SIZE: TerminalDisplaySize,
+    F: TerminalFont,
+    C: AsMut<[TerminalCell]>,
+{
+    /// Print UTF-8 encoded text to the display.
+    ///
+    /// Characters may be split across writes. Invalid UTF-8 sequences are printed as a single
+    /// unknown character.
+    async fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        if self.mode.utf8_pending.1 > 0 {
+            let used = self.write_pending_char(buf).await?;
+            if used > 0 {
+                return Ok(used);
             }
         }
 
-        rotated
+        match core::str::from_utf8(buf) {
+            Ok(s) => {
+                self.write_str(s).await?;
+                Ok(s.len())
+            }
+            Err(e) if e.valid_up_to() > 0 => {
+                let valid = core::str::from_utf8(&buf[..e.valid_up_to()]).unwrap_or_default();
+                self.write_str(valid).await?;
+                Ok(valid.len())
+            }
+            Err(e) => match e.error_len() {
+                Some(len) => {
+                    self.print_char(char::REPLACEMENT_CHARACTER).await?;
+                    Ok(len)
+                }
+                // The character continues in the next write
+                None => {
+                    self.mode.utf8_pending.0[..buf.len()].copy_from_slice(buf);
+                    self.mode.utf8_pending.1 = buf.len() as u8;
+                    Ok(buf.len())
+                }
+            },
+        }
     }
 }
 
-use embassy_futures::block_on;
-impl<DI, SIZE> core::fmt::Write for Ssd1306<DI, SIZE, TerminalMode>
+#[cfg(feature = "io")]
+impl<DI, SIZE, F, C> Ssd1306<DI, SIZE, TerminalMode<F, C>>
 where
     DI: WriteOnlyDataCommand<Error = DisplayError>,
     SIZE: TerminalDisplaySize,
+    F: TerminalFont,
+    C: AsMut<[TerminalCell]>,
 {
-    fn write_str(&mut self, s: &str) -> Result<(), fmt::Error> {
-        s.chars().map(move |c| block_on(self.print_char(c))).last();
+    /// Complete the character started by the previous write using the start of `buf`, and return
+    /// the number of bytes of `buf` that were used
+    async fn write_pending_char(&mut self, buf: &[u8]) -> Result<usize, TerminalModeError> {
+        let (start, pending) = self.mode.utf8_pending;
+        let pending = pending as usize;
+        let len = (pending + buf.len()).min(4);
+        let mut bytes = [0; 4];
+        bytes[..pending].copy_from_slice(&start[..pending]);
+        bytes[pending..len].copy_from_slice(&buf[..len - pending]);
+
+        let decoded = core::str::from_utf8(&bytes[..len]);
+        let valid = decoded.map_or_else(|e| e.valid_up_to(), str::len);
+        let first = core::str::from_utf8(&bytes[..valid])
+            .ok()
+            .and_then(|s| s.chars().next());
+
+        if let Some(c) = first {
+            self.mode.utf8_pending.1 = 0;
+            self.print_char(c).await?;
+            return Ok(c.len_utf8() - pending);
+        }
+
+        match decoded.err().and_then(|e| e.error_len()) {
+            // Still incomplete, so all of `buf` belongs to the character
+            None => {
+                self.mode.utf8_pending.0[..len].copy_from_slice(&bytes[..len]);
+                self.mode.utf8_pending.1 = len as u8;
+                Ok(len - pending)
+            }
+            Some(invalid) => {
+                self.mode.utf8_pending.1 = 0;
+                self.print_char(char::REPLACEMENT_CHARACTER).await?;
+                Ok(invalid.saturating_sub(pending))
+            }
+        }
+    }
+}
+
+/// Formatter output which keeps the part of the formatted text following the first `skip` bytes
+/// that fits into the buffer
+struct FmtChunk {
+    skip: usize,
+    buf: [u8; FMT_CHUNK_LEN],
+    len: usize,
+    full: bool,
+}
+
+impl fmt::Write for FmtChunk {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            let c_len = c.len_utf8();
+            if self.skip > 0 {
+                // The text may differ from the previous time it was formatted, so stop skipping
+                // rather than skipping part of a character
+                if let Some(skip) = self.skip.checked_sub(c_len) {
+                    self.skip = skip;
+                    continue;
+                }
+                self.skip = 0;
+            }
+            if self.len + c_len > self.buf.len() {
+                // Abort formatting, the rest is printed with the next chunk
+                self.full = true;
+                return Err(fmt::Error);
+            }
+            c.encode_utf8(&mut self.buf[self.len..]);
+            self.len += c_len;
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,150 @@
+//! Parser for the ANSI escape sequences understood by [`TerminalMode`](super::TerminalMode).
+
+/// Maximum number of parameters kept for a control sequence. Further parameters are ignored.
+const MAX_PARAMS: usize = 8;
+
+/// Escape character, which starts an escape sequence
+const ESC: char = '\x1b';
+
+/// What to do with a character passed to the [`Parser`]
+pub(super) enum Action {
+    /// Draw a printable character
+    Print(char),
+    /// Execute a control character
+    Control(char),
+    /// Execute an escape sequence, identified by its final character
+    Escape(char),
+    /// Execute a control sequence (`ESC [ ...`)
+    Csi(Csi),
+}
+
+/// A complete control sequence
+#[derive(Copy, Clone, Debug)]
+pub(super) struct Csi {
+    params: [u16; MAX_PARAMS],
+    len: usize,
+    /// The final character, which identifies the function
+    pub(super) function: char,
+}
+
+impl Csi {
+    /// Get a parameter, which is 0 if it was omitted
+    pub(super) fn param(&self, index: usize) -> u16 {
+        self.params().get(index).copied().unwrap_or(0)
+    }
+
+    /// Get a parameter used as a count or position, which is 1 if it was omitted or 0
+    pub(super) fn count(&self, index: usize) -> u16 {
+        self.param(index).max(1)
+    }
+
+    /// Get all parameters of the sequence
+    pub(super) fn params(&self) -> &[u16] {
+        &self.params[..self.len]
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+enum State {
+    #[default]
+    Ground,
+    Escape,
+    EscapeIntermediate,
+    Csi,
+}
+
+/// Splits text into printable characters, control characters and escape sequences.
+///
+/// Sequences which aren't complete are kept in the parser state, so text can be passed in one
+/// character at a time.
+#[derive(Copy, Clone, Debug, Default)]
+pub(super) struct Parser {
+    state: State,
+    params: [u16; MAX_PARAMS],
+    /// Index of the parameter currently being parsed
+    index: usize,
+    /// Set for private and intermediate sequences, which aren't supported
+    ignore: bool,
+}
+
+impl Parser {
+    /// Whether the parser is not within an escape sequence
+    pub(super) fn is_ground(&self) -> bool {
+        self.state == State::Ground
+    }
+
+    /// Parse the next character, returning an action once a character or sequence is complete
+    pub(super) fn advance(&mut self, c: char) -> Option<Action> {
+        match c {
+            ESC => {
+                self.state = State::Escape;
+                return None;
+            }
+            // CAN and SUB abort a sequence
+            '\x18' | '\x1a' => {
+                self.state = State::Ground;
+                return None;
+            }
+            // Other control characters are executed even within a sequence
+            _ if c.is_control() => return Some(Action::Control(c)),
+            _ => {}
+        }
+
+        match self.state {
+            State::Ground => Some(Action::Print(c)),
+            State::Escape => match c {
+                '[' => {
+                    self.state = State::Csi;
+                    self.params = [0; MAX_PARAMS];
+                    self.index = 0;
+                    self.ignore = false;
+                    None
+                }
+                ' '..='/' => {
+                    self.state = State::EscapeIntermediate;
+                    None
+                }
+                _ => {
+                    self.state = State::Ground;
+                    c.is_ascii().then_some(Action::Escape(c))
+                }
+            },
+            State::EscapeIntermediate => {
+                // Character set designations and the like are ignored
+                if !matches!(c, ' '..='/') {
+                    self.state = State::Ground;
+                }
+                None
+            }
+            State::Csi => match c {
+                '0'..='9' => {
+                    if let Some(param) = self.params.get_mut(self.index) {
+                        let digit = c as u16 - '0' as u16;
+                        *param = param.saturating_mul(10).saturating_add(digit);
+                    }
+                    None
+                }
+                ';' => {
+                    self.index += 1;
+                    None
+                }
+                ' '..='/' | '<'..='?' | ':' => {
+                    self.ignore = true;
+                    None
+                }
+                '@'..='~' => {
+                    self.state = State::Ground;
+                    (!self.ignore).then_some(Action::Csi(Csi {
+                        params: self.params,
+                        len: (self.index + 1).min(MAX_PARAMS),
+                        function: c,
+                    }))
+                }
+                _ => {
+                    self.state = State::Ground;
+                    None
+                }
+            },
+        }
+    }
+}
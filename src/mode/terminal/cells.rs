@@ -0,0 +1,114 @@
+//! Contents of the character cells shown by [`TerminalMode`](super::TerminalMode).
+//!
+//! The display RAM can't be read back, so the cell contents are kept to redraw a cell after the
+//! cursor was drawn over it.
+
+use super::Attributes;
+
+/// Contents of a character cell
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(super) enum Cell {
+    /// A character, drawn using the given attributes
+    Char(char, Attributes),
+    /// Every byte of the cell set to the same value
+    Fill(u8),
+}
+
+impl Cell {
+    /// An empty cell
+    pub(super) const BLANK: Self = Cell::Char(' ', Attributes::new());
+
+    /// Cells filled with a byte. Cells filled with 0 are empty.
+    pub(super) fn fill(byte: u8) -> Self {
+        if byte == 0 {
+            Self::BLANK
+        } else {
+            Cell::Fill(byte)
+        }
+    }
+}
+
+/// Storage for the contents of a character cell of [`TerminalMode`](super::TerminalMode).
+///
+/// The cursor can only be shown over cells whose contents are kept, see
+/// [`with_cells`](crate::Ssd1306::with_cells).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TerminalCell(Cell);
+
+impl TerminalCell {
+    /// An empty cell
+    pub const BLANK: Self = Self(Cell::BLANK);
+}
+
+impl Default for TerminalCell {
+    fn default() -> Self {
+        Self::BLANK
+    }
+}
+
+/// The cells of the display, row by row
+#[derive(Copy, Clone, Debug)]
+pub(super) struct Cells<C> {
+    cells: C,
+    /// Number of cells in a row
+    width: u8,
+}
+
+impl<C> Cells<C>
+where
+    C: AsMut<[TerminalCell]>,
+{
+    /// Keep the cells in `cells`
+    pub(super) fn new(cells: C) -> Self {
+        Self { cells, width: 0 }
+    }
+
+    /// Keep the cells in `cells` instead, which are empty afterwards
+    pub(super) fn with_storage<C2>(self, cells: C2) -> Cells<C2>
+    where
+        C2: AsMut<[TerminalCell]>,
+    {
+        let mut cells = Cells::new(cells);
+        cells.reset(self.width);
+        cells
+    }
+
+    /// Empty all cells, which now form rows of `width` characters
+    pub(super) fn reset(&mut self, width: u8) {
+        self.width = width;
+        self.cells.as_mut().fill(TerminalCell::BLANK);
+    }
+
+    /// Whether the contents of any cell are kept
+    pub(super) fn is_empty(&mut self) -> bool {
+        self.cells.as_mut().is_empty()
+    }
+
+    /// Get the contents of a cell, unless they aren't kept
+    pub(super) fn get(&mut self, column: u8, row: u8) -> Option<Cell> {
+        let index = self.index(column, row)?;
+        Some(self.cells.as_mut()[index].0)
+    }
+
+    /// Set the contents of a cell
+    pub(super) fn set(&mut self, column: u8, row: u8, cell: Cell) {
+        if let Some(index) = self.index(column, row) {
+            self.cells.as_mut()[index] = TerminalCell(cell);
+        }
+    }
+
+    /// Move all rows up by one, leaving the last of `rows` rows empty
+    pub(super) fn scroll(&mut self, rows: u8) {
+        let width = self.width as usize;
+        let cells = self.cells.as_mut();
+        let end = (rows as usize * width).min(cells.len());
+
+        cells.copy_within(width.min(end)..end, 0);
+        cells[end.saturating_sub(width)..end].fill(TerminalCell::BLANK);
+    }
+
+    fn index(&mut self, column: u8, row: u8) -> Option<usize> {
+        let index = row as usize * self.width as usize + column as usize;
+        (column < self.width && index < self.cells.as_mut().len()).then_some(index)
+    }
+}
@@ -6,10 +6,11 @@ pub use crate::WriteOnlyDataCommand;
 
 pub use super::{
     brightness::Brightness,
+    init_config::InitConfig,
     mode::DisplayConfig,
     rotation::DisplayRotation,
     size::{
-        DisplaySize, DisplaySize128x32, DisplaySize128x64, DisplaySize64x48, DisplaySize72x40,
-        DisplaySize96x16,
+        CustomSize, DisplaySize, DisplaySize128x32, DisplaySize128x64, DisplaySize64x32,
+        DisplaySize64x48, DisplaySize72x40, DisplaySize96x16, RuntimeSize,
     },
 };
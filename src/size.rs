@@ -1,6 +1,7 @@
 //! Display size.
 
 use super::command::Command;
+use crate::DisplayError;
 
 /// Workaround trait, since `Default` is only implemented to arrays up to 32 of size
 pub trait NewZeroed {
@@ -51,10 +52,32 @@ pub trait DisplaySize {
     fn int_iref(&self) -> Option<Command> {
         None
     }
+
+    /// Width in pixels, which is [`WIDTH`](Self::WIDTH) unless the size is chosen at runtime
+    fn width(&self) -> u8 {
+        Self::WIDTH
+    }
+
+    /// Height in pixels, which is [`HEIGHT`](Self::HEIGHT) unless the size is chosen at runtime
+    fn height(&self) -> u8 {
+        Self::HEIGHT
+    }
+
+    /// Horizontal offset in pixels, which is [`OFFSETX`](Self::OFFSETX) unless the size is chosen
+    /// at runtime
+    fn offset_x(&self) -> u8 {
+        Self::OFFSETX
+    }
+
+    /// Vertical offset in pixels, which is [`OFFSETY`](Self::OFFSETY) unless the size is chosen at
+    /// runtime
+    fn offset_y(&self) -> u8 {
+        Self::OFFSETY
+    }
 }
 
-// /// Size information for the common 128x64 variants
-// #[derive(Debug, Copy, Clone)]
+/// Size information for the common 128x64 variants
+#[derive(Debug, Copy, Clone)]
 pub struct DisplaySize128x64;
 impl DisplaySize for DisplaySize128x64 {
     const WIDTH: u8 = 128;
@@ -140,3 +163,197 @@ impl DisplaySize for DisplaySize64x32 {
         Command::ComPinConfig(true, false)
     }
 }
+
+/// Size information for other panels, given by const parameters.
+///
+/// `W` x `H` is the size of the panel in pixels, and `OX` and `OY` are the position of its top
+/// left corner in the 128x64 pixel display RAM of the driver. E.g. 102x64 glass centered on a
+/// 128x64 driver:
+///
+/// ```rust
+/// use ssd1306_async::prelude::*;
+///
+/// type DisplaySize102x64 = CustomSize<102, 64, 13, 0>;
+/// ```
+///
+/// The display start line is moved down by `OY` when initialising the display, so the top row of
+/// the panel shows row `OY` of the display RAM.
+///
+/// The COM pin configuration is chosen by height, using the alternative configuration for panels
+/// higher than 32 pixels. Some smaller panels, like the 64x32 ones, are wired for the alternative
+/// configuration too, which can be selected using
+/// [`InitConfig::com_pin_cfg`](crate::prelude::InitConfig::com_pin_cfg).
+///
+/// Sizes which don't fit into the display RAM are rejected when the display is built. The height
+/// and vertical offset must be multiples of 8, as the display RAM is written in 8 pixel high pages,
+/// and the driver supports 16 to 64 rows:
+///
+/// ```rust,compile_fail
+/// use ssd1306_async::prelude::*;
+///
+/// // 72 rows don't fit into the display RAM
+/// let height = <CustomSize<128, 72> as DisplaySize>::HEIGHT;
+/// ```
+///
+/// Stable Rust can't size an array using the const parameters, so the default buffer of
+/// [`into_buffered_graphics_mode`](crate::Ssd1306::into_buffered_graphics_mode) reserves the 1024
+/// bytes of the whole display RAM, like for [`RuntimeSize`]. Use
+/// [`into_buffered_graphics_mode_with_buffer`](crate::Ssd1306::into_buffered_graphics_mode_with_buffer)
+/// to provide a buffer of [`BUFFER_LEN`](Self::BUFFER_LEN) bytes instead.
+#[derive(Debug, Copy, Clone)]
+pub struct CustomSize<const W: u8, const H: u8, const OX: u8 = 0, const OY: u8 = 0>;
+
+impl<const W: u8, const H: u8, const OX: u8, const OY: u8> CustomSize<W, H, OX, OY> {
+    /// Fails to compile if the panel doesn't fit into the display RAM
+    const VALID: () = {
+        assert!(W > 0, "the width must not be 0");
+        assert!(
+            W as u16 + OX as u16 <= 128,
+            "the panel must fit into the 128 columns of the display RAM"
+        );
+        assert!(
+            H >= 16 && H % 8 == 0,
+            "the height must be a multiple of 8, and at least 16"
+        );
+        assert!(OY % 8 == 0, "the vertical offset must be a multiple of 8");
+        assert!(
+            H as u16 + OY as u16 <= 64,
+            "the panel must fit into the 64 rows of the display RAM"
+        );
+    };
+
+    /// Number of bytes needed to buffer the contents of the panel
+    pub const BUFFER_LEN: usize = {
+        let () = Self::VALID;
+        W as usize * H as usize / 8
+    };
+}
+
+impl<const W: u8, const H: u8, const OX: u8, const OY: u8> DisplaySize
+    for CustomSize<W, H, OX, OY>
+{
+    const WIDTH: u8 = {
+        let () = Self::VALID;
+        W
+    };
+    const HEIGHT: u8 = {
+        let () = Self::VALID;
+        H
+    };
+    const OFFSETX: u8 = OX;
+    const OFFSETY: u8 = OY;
+    type Buffer = [u8; 1024];
+
+    fn com_pin_cfg(&self) -> Command {
+        Command::ComPinConfig(Self::HEIGHT > 32, false)
+    }
+}
+
+/// Size information chosen at runtime, e.g. to support several panel variants with one firmware
+/// image.
+///
+/// The associated constants of [`DisplaySize`] describe the largest supported panel of 128x64
+/// pixels, so the default buffer of
+/// [`into_buffered_graphics_mode`](crate::Ssd1306::into_buffered_graphics_mode) fits every size.
+/// Use [`into_buffered_graphics_mode_with_buffer`](crate::Ssd1306::into_buffered_graphics_mode_with_buffer)
+/// to provide a buffer of [`buffer_len`](Self::buffer_len) bytes instead.
+///
+/// ```rust
+/// use ssd1306_async::{prelude::*, DisplayError};
+///
+/// # fn main() -> Result<(), DisplayError> {
+/// # let panel_id = 1;
+/// let size = match panel_id {
+///     0 => RuntimeSize::new(128, 64, 0, 0)?,
+///     _ => RuntimeSize::new(72, 40, 28, 0)?,
+/// };
+/// assert_eq!(size.buffer_len(), 360);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The terminal mode requires a size known at compile time.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RuntimeSize {
+    width: u8,
+    height: u8,
+    offset_x: u8,
+    offset_y: u8,
+    com_pin_cfg: (bool, bool),
+}
+
+impl RuntimeSize {
+    /// Create size information for a panel of `width` x `height` pixels with its top left corner
+    /// at (`offset_x`, `offset_y`) in the 128x64 pixel display RAM.
+    ///
+    /// The same sizes as for [`CustomSize`] are supported, otherwise
+    /// [`DisplayError::OutOfBoundsError`] is returned. The COM pin configuration and display start
+    /// line are chosen like for `CustomSize`.
+    pub const fn new(
+        width: u8,
+        height: u8,
+        offset_x: u8,
+        offset_y: u8,
+    ) -> Result<Self, DisplayError> {
+        if width == 0
+            || width as u16 + offset_x as u16 > 128
+            || height < 16
+            || height % 8 != 0
+            || offset_y % 8 != 0
+            || height as u16 + offset_y as u16 > 64
+        {
+            return Err(DisplayError::OutOfBoundsError);
+        }
+
+        Ok(Self {
+            width,
+            height,
+            offset_x,
+            offset_y,
+            com_pin_cfg: (height > 32, false),
+        })
+    }
+
+    /// Set the COM pin configuration of the panel.
+    ///
+    /// See [`InitConfig::com_pin_cfg`](crate::prelude::InitConfig::com_pin_cfg) for the
+    /// parameters.
+    pub const fn with_com_pin_cfg(self, alternative: bool, left_right_remap: bool) -> Self {
+        Self {
+            com_pin_cfg: (alternative, left_right_remap),
+            ..self
+        }
+    }
+
+    /// Number of bytes needed to buffer the contents of the panel
+    pub const fn buffer_len(&self) -> usize {
+        self.width as usize * self.height as usize / 8
+    }
+}
+
+impl DisplaySize for RuntimeSize {
+    const WIDTH: u8 = 128;
+    const HEIGHT: u8 = 64;
+    type Buffer = [u8; Self::WIDTH as usize * Self::HEIGHT as usize / 8];
+
+    fn com_pin_cfg(&self) -> Command {
+        Command::ComPinConfig(self.com_pin_cfg.0, self.com_pin_cfg.1)
+    }
+
+    fn width(&self) -> u8 {
+        self.width
+    }
+
+    fn height(&self) -> u8 {
+        self.height
+    }
+
+    fn offset_x(&self) -> u8 {
+        self.offset_x
+    }
+
+    fn offset_y(&self) -> u8 {
+        self.offset_y
+    }
+}
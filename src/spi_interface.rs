@@ -3,7 +3,7 @@
 use crate::{DataFormat, DisplayError, WriteOnlyDataCommand};
 type Result = core::result::Result<(), DisplayError>;
 use core::convert::Infallible;
-use embedded_hal::digital::OutputPin;
+use embedded_hal::{digital::OutputPin, spi::Error};
 use embedded_hal_async::spi::SpiDevice;
 
 async fn send_u8<SPI>(spi: &mut SPI, words: DataFormat<'_>) -> Result
@@ -14,30 +14,30 @@ where
         DataFormat::U8(slice) => spi
             .write(slice)
             .await
-            .map_err(|_| DisplayError::BusWriteError),
+            .map_err(|e| DisplayError::from(e.kind())),
         DataFormat::U16(slice) => {
             use byte_slice_cast::*;
             spi.write(slice.as_byte_slice())
                 .await
-                .map_err(|_| DisplayError::BusWriteError)
+                .map_err(|e| DisplayError::from(e.kind()))
         }
         DataFormat::U16LE(slice) => {
             use byte_slice_cast::*;
-            for v in slice.as_mut() {
+            for v in slice.iter_mut() {
                 *v = v.to_le();
             }
             spi.write(slice.as_byte_slice())
                 .await
-                .map_err(|_| DisplayError::BusWriteError)
+                .map_err(|e| DisplayError::from(e.kind()))
         }
         DataFormat::U16BE(slice) => {
             use byte_slice_cast::*;
-            for v in slice.as_mut() {
+            for v in slice.iter_mut() {
                 *v = v.to_be();
             }
             spi.write(slice.as_byte_slice())
                 .await
-                .map_err(|_| DisplayError::BusWriteError)
+                .map_err(|e| DisplayError::from(e.kind()))
         }
         DataFormat::U8Iter(iter) => {
             let mut buf = [0; 32];
@@ -50,7 +50,7 @@ where
                 if i == buf.len() {
                     spi.write(&buf)
                         .await
-                        .map_err(|_| DisplayError::BusWriteError)?;
+                        .map_err(|e| DisplayError::from(e.kind()))?;
                     i = 0;
                 }
             }
@@ -58,7 +58,7 @@ where
             if i > 0 {
                 spi.write(&buf[..i])
                     .await
-                    .map_err(|_| DisplayError::BusWriteError)?;
+                    .map_err(|e| DisplayError::from(e.kind()))?;
             }
 
             Ok(())
@@ -73,17 +73,17 @@ where
                 i += 1;
 
                 if i == buf.len() {
-                    spi.write(&buf.as_byte_slice())
+                    spi.write(buf.as_byte_slice())
                         .await
-                        .map_err(|_| DisplayError::BusWriteError)?;
+                        .map_err(|e| DisplayError::from(e.kind()))?;
                     i = 0;
                 }
             }
 
             if i > 0 {
-                spi.write(&buf[..i].as_byte_slice())
+                spi.write(buf[..i].as_byte_slice())
                     .await
-                    .map_err(|_| DisplayError::BusWriteError)?;
+                    .map_err(|e| DisplayError::from(e.kind()))?;
             }
 
             Ok(())
@@ -99,17 +99,17 @@ where
                 i += 1;
 
                 if i == len {
-                    spi.write(&buf.as_byte_slice())
+                    spi.write(buf.as_byte_slice())
                         .await
-                        .map_err(|_| DisplayError::BusWriteError)?;
+                        .map_err(|e| DisplayError::from(e.kind()))?;
                     i = 0;
                 }
             }
 
             if i > 0 {
-                spi.write(&buf[..i].as_byte_slice())
+                spi.write(buf[..i].as_byte_slice())
                     .await
-                    .map_err(|_| DisplayError::BusWriteError)?;
+                    .map_err(|e| DisplayError::from(e.kind()))?;
             }
 
             Ok(())
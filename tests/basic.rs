@@ -0,0 +1,258 @@
+use embassy_futures::block_on;
+use ssd1306_async::{
+    controller::Controller, emulator::EmulatedDisplay, prelude::*, DataFormat, DisplayError,
+    Ssd1306,
+};
+
+mod common;
+
+use common::ROTATIONS;
+
+/// Fill the display and check that exactly the visible part of the display RAM was written
+fn fills_visible_area<SIZE>(controller: Controller, size: SIZE)
+where
+    SIZE: DisplaySize + Copy,
+{
+    for (rotation, _) in ROTATIONS {
+        let mut display = Ssd1306::new(
+            EmulatedDisplay::new_with_controller(controller),
+            size,
+            rotation,
+        )
+        .with_controller(controller);
+        block_on(display.init()).unwrap();
+        block_on(display.fill(0xA5)).unwrap();
+
+        let (width, height) = (size.width(), size.height());
+        let offset_x = match rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate270 => size.offset_x(),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate180 => {
+                SIZE::DRIVER_COLS - width - size.offset_x()
+            }
+        } + controller.column_offset();
+        let columns = offset_x as usize..(offset_x + width) as usize;
+        let pages = size.offset_y() / 8..(size.offset_y() + height).div_ceil(8);
+
+        let emulator = display.release();
+        for page in 0..8 {
+            for (column, &byte) in emulator.ram(page).iter().enumerate() {
+                let visible = pages.contains(&page) && columns.contains(&column);
+                assert_eq!(
+                    byte,
+                    if visible { 0xA5 } else { 0 },
+                    "{:?} {}x{} {:?} page {} column {}",
+                    controller,
+                    width,
+                    height,
+                    rotation,
+                    page,
+                    column
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn fill_writes_visible_area() {
+    fills_visible_area(Controller::Ssd1306, DisplaySize128x64);
+    fills_visible_area(Controller::Ssd1306, DisplaySize128x32);
+    fills_visible_area(Controller::Ssd1306, DisplaySize96x16);
+    fills_visible_area(Controller::Ssd1306, DisplaySize72x40);
+    fills_visible_area(Controller::Ssd1306, DisplaySize64x48);
+    fills_visible_area(Controller::Ssd1306, DisplaySize64x32);
+    fills_visible_area(Controller::Sh1106, DisplaySize128x64);
+    fills_visible_area(Controller::Ssd1306, CustomSize::<102, 64, 13>);
+    fills_visible_area(
+        Controller::Ssd1306,
+        RuntimeSize::new(80, 48, 24, 0).unwrap(),
+    );
+}
+
+#[test]
+fn clear_turns_off_every_pixel() {
+    for (rotation, _) in ROTATIONS {
+        let mut display = Ssd1306::new(EmulatedDisplay::new(), DisplaySize72x40, rotation);
+        block_on(display.init()).unwrap();
+        block_on(display.fill(0xFF)).unwrap();
+        block_on(display.clear()).unwrap();
+
+        let emulator = display.release();
+        assert!((0..8).all(|page| emulator.ram(page).iter().all(|&byte| byte == 0)));
+    }
+}
+
+#[test]
+fn runtime_size_must_fit_display_ram() {
+    assert!(RuntimeSize::new(128, 64, 0, 0).is_ok());
+    assert!(RuntimeSize::new(64, 32, 64, 32).is_ok());
+
+    for (width, height, offset_x, offset_y) in [
+        (0, 64, 0, 0),
+        (128, 64, 1, 0),
+        (128, 8, 0, 0),
+        (128, 36, 0, 0),
+        (128, 32, 0, 4),
+        (128, 64, 0, 8),
+    ] {
+        assert!(matches!(
+            RuntimeSize::new(width, height, offset_x, offset_y),
+            Err(DisplayError::OutOfBoundsError)
+        ));
+    }
+}
+
+#[test]
+fn runtime_size_sets_com_pin_config() {
+    let init = |size: RuntimeSize| {
+        let mut display = Ssd1306::new(EmulatedDisplay::new(), size, DisplayRotation::Rotate0);
+        block_on(display.init()).unwrap();
+        let emulator = display.release();
+        (emulator.multiplex(), emulator.com_pin_cfg())
+    };
+
+    assert_eq!(init(RuntimeSize::new(128, 64, 0, 0).unwrap()), (63, 0x12));
+    assert_eq!(init(RuntimeSize::new(128, 32, 0, 0).unwrap()), (31, 0x02));
+    assert_eq!(
+        init(
+            RuntimeSize::new(64, 32, 32, 0)
+                .unwrap()
+                .with_com_pin_cfg(true, false)
+        ),
+        (31, 0x12)
+    );
+}
+
+#[test]
+fn buffer_must_fit_display() {
+    let size = RuntimeSize::new(72, 40, 28, 0).unwrap();
+    let mut buffer = [0; 360];
+
+    let mut display = Ssd1306::new(EmulatedDisplay::new(), size, DisplayRotation::Rotate0)
+        .into_buffered_graphics_mode_with_buffer(&mut buffer[..359]);
+    assert!(matches!(
+        block_on(display.init()),
+        Err(DisplayError::OutOfBoundsError)
+    ));
+    display.set_pixel(0, 0, true);
+    assert!(matches!(
+        block_on(display.flush()),
+        Err(DisplayError::OutOfBoundsError)
+    ));
+
+    let mut display = Ssd1306::new(EmulatedDisplay::new(), size, DisplayRotation::Rotate0)
+        .into_buffered_graphics_mode_with_buffer(&mut buffer[..]);
+    block_on(display.init()).unwrap();
+    display.set_pixel(71, 39, true);
+    // Pixels outside the display aren't drawn into the buffer
+    display.set_pixel(72, 0, true);
+    block_on(display.flush()).unwrap();
+    let emulator = display.release();
+    assert!(emulator.frame().pixel(28 + 71, 39));
+    assert_eq!(buffer[359], 0x80);
+    assert_eq!(buffer.iter().filter(|&&byte| byte != 0).count(), 1);
+}
+
+#[test]
+fn custom_size_fits_buffer_len() {
+    type DisplaySize80x48 = CustomSize<80, 48, 24>;
+    let mut buffer = [0; DisplaySize80x48::BUFFER_LEN];
+    assert_eq!(buffer.len(), 480);
+
+    let mut display = Ssd1306::new(
+        EmulatedDisplay::new(),
+        CustomSize::<80, 48, 24>,
+        DisplayRotation::Rotate0,
+    )
+    .into_buffered_graphics_mode_with_buffer(&mut buffer);
+    block_on(display.init()).unwrap();
+    display.set_pixel(79, 47, true);
+    block_on(display.flush()).unwrap();
+    assert!(display.release().frame().pixel(24 + 79, 47));
+    assert_eq!(buffer[479], 0x80);
+}
+
+#[test]
+fn displays_share_a_buffer() {
+    let mut buffer = [0; 1024];
+
+    let mut display = Ssd1306::new(
+        EmulatedDisplay::new(),
+        DisplaySize128x64,
+        DisplayRotation::Rotate0,
+    )
+    .into_buffered_graphics_mode_with_buffer(&mut buffer);
+    block_on(display.init()).unwrap();
+    display.set_pixel(0, 0, true);
+    block_on(display.flush()).unwrap();
+    let (first, buffer) = display.release_buffer();
+
+    let mut display = Ssd1306::new(
+        EmulatedDisplay::new(),
+        DisplaySize128x64,
+        DisplayRotation::Rotate0,
+    )
+    .into_buffered_graphics_mode_with_buffer(buffer);
+    block_on(display.init()).unwrap();
+    display.clear();
+    display.set_pixel(127, 63, true);
+    block_on(display.flush()).unwrap();
+    let (second, _) = display.release_buffer();
+
+    let (first, second) = (first.release().frame(), second.release().frame());
+    assert!(first.pixel(0, 0) && !first.pixel(127, 63));
+    assert!(!second.pixel(0, 0) && second.pixel(127, 63));
+}
+
+/// Emulated display which counts the bytes of pixel data sent to it
+struct CountingDisplay {
+    emulator: EmulatedDisplay,
+    data_bytes: usize,
+}
+
+impl WriteOnlyDataCommand for CountingDisplay {
+    type Error = DisplayError;
+
+    async fn send_commands(&mut self, cmds: DataFormat<'_>) -> Result<(), Self::Error> {
+        self.emulator.send_commands(cmds).await
+    }
+
+    async fn send_data(&mut self, buf: DataFormat<'_>) -> Result<(), Self::Error> {
+        if let DataFormat::U8(slice) = &buf {
+            self.data_bytes += slice.len();
+        }
+        self.emulator.send_data(buf).await
+    }
+}
+
+#[test]
+fn flush_sends_changed_columns_of_each_page() {
+    for (rotation, _) in ROTATIONS {
+        let interface = CountingDisplay {
+            emulator: EmulatedDisplay::new(),
+            data_bytes: 0,
+        };
+        let mut display =
+            Ssd1306::new(interface, DisplaySize128x64, rotation).into_buffered_graphics_mode();
+        block_on(display.init()).unwrap();
+        block_on(display.flush()).unwrap();
+
+        // Opposite corners only need a byte each, instead of the whole display
+        let (width, height) = display.dimensions();
+        let (right, bottom) = (width as u32 - 1, height as u32 - 1);
+        display.set_pixel(0, 0, true);
+        display.set_pixel(right, bottom, true);
+        block_on(display.flush()).unwrap();
+
+        let interface = display.release();
+        assert_eq!(interface.data_bytes, 1024 + 2, "{:?}", rotation);
+        let frame = interface.emulator.frame();
+        let lit = (0..128).flat_map(|x| (0..64).map(move |y| (x, y)));
+        assert_eq!(
+            lit.filter(|&(x, y)| frame.pixel(x, y)).count(),
+            2,
+            "{:?}",
+            rotation
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use core::convert::Infallible;
+use embedded_hal::i2c::{ErrorType, I2c, Operation};
+use ssd1306_async::{
+    blocking::{block_once, Blocking},
+    emulator::EmulatedDisplay,
+    prelude::*,
+    DataFormat, I2CDisplayInterface, Ssd1306,
+};
+use std::vec::Vec;
+
+/// Blocking I2C bus which forwards all writes to an emulated display
+#[derive(Default)]
+struct EmulatedI2c {
+    display: EmulatedDisplay,
+    addresses: Vec<u8>,
+}
+
+impl ErrorType for EmulatedI2c {
+    type Error = Infallible;
+}
+
+impl I2c for EmulatedI2c {
+    fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        for op in operations {
+            if let Operation::Write(bytes) = op {
+                self.addresses.push(address);
+
+                // The first byte selects between commands and data
+                let payload = DataFormat::U8(&bytes[1..]);
+                let result = if bytes[0] == 0x40 {
+                    block_once(self.display.send_data(payload))
+                } else {
+                    block_once(self.display.send_commands(payload))
+                };
+                result.unwrap().unwrap();
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn draws_without_executor() {
+    let interface = I2CDisplayInterface::new(Blocking::new(EmulatedI2c::default()));
+    let mut display = Ssd1306::new(interface, DisplaySize128x32, DisplayRotation::Rotate0)
+        .into_buffered_graphics_mode();
+
+    block_once(display.init()).unwrap().unwrap();
+    for i in 0..32 {
+        display.set_pixel(i * 2, i, true);
+    }
+    block_once(display.flush()).unwrap().unwrap();
+
+    let i2c = display.release().release().release();
+    assert!(i2c.addresses.iter().all(|&address| address == 0x3C));
+
+    let frame = i2c.display.frame();
+    for i in 0..32 {
+        assert!(frame.pixel(i * 2, i));
+        assert!(!frame.pixel(i * 2 + 1, i));
+    }
+}
+
+#[test]
+fn waiting_requires_executor() {
+    assert_eq!(block_once(core::future::pending::<()>()), None);
+    assert_eq!(block_once(core::future::ready(1)), Some(1));
+}
@@ -0,0 +1,11 @@
+//! Helpers shared by the integration tests.
+
+use ssd1306_async::prelude::DisplayRotation;
+
+/// Every display rotation, along with its name in the golden image file names
+pub const ROTATIONS: [(DisplayRotation, &str); 4] = [
+    (DisplayRotation::Rotate0, "rotate0"),
+    (DisplayRotation::Rotate90, "rotate90"),
+    (DisplayRotation::Rotate180, "rotate180"),
+    (DisplayRotation::Rotate270, "rotate270"),
+];
@@ -0,0 +1,121 @@
+use core::fmt::Write;
+use embassy_futures::block_on;
+use ssd1306_async::{
+    emulator::{EmulatedDisplay, Frame},
+    mode::{Attributes, Overflow, TextConsole},
+    prelude::*,
+    Ssd1306,
+};
+
+mod common;
+
+use common::ROTATIONS;
+
+const TEXT: &str = "Rust!\nABCDEFGHIJKLMNOPQRSTUVWXYZ\rab\n\n0123";
+
+/// Print text to a console in the buffer of a 128x64 display
+fn render_console(
+    rotation: DisplayRotation,
+    mut console: TextConsole,
+    text: &str,
+    background: &[(u32, u32)],
+) -> Frame {
+    let mut display = Ssd1306::new(EmulatedDisplay::new(), DisplaySize128x64, rotation)
+        .into_buffered_graphics_mode();
+    block_on(display.init()).unwrap();
+    for &(x, y) in background {
+        display.set_pixel(x, y, true);
+    }
+
+    write!(console.writer(&mut display), "{}", text).unwrap();
+    block_on(display.flush()).unwrap();
+    display.release().frame()
+}
+
+#[test]
+fn console_matches_terminal_mode() {
+    for (rotation, _) in ROTATIONS {
+        for overflow in [Overflow::Wrap, Overflow::Scroll] {
+            let mut terminal = Ssd1306::new(EmulatedDisplay::new(), DisplaySize128x64, rotation)
+                .into_terminal_mode();
+            terminal.set_overflow(overflow);
+            block_on(terminal.init()).unwrap();
+            block_on(terminal.write_str(TEXT)).unwrap();
+            block_on(terminal.write_str(&TEXT.repeat(4))).unwrap();
+
+            // Rotated terminals can't scroll
+            let (width, height) = match rotation {
+                DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => (128, 64),
+                DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => (64, 128),
+            };
+            let mut console = TextConsole::new((0, 0), (width, height));
+            if matches!(
+                rotation,
+                DisplayRotation::Rotate0 | DisplayRotation::Rotate180
+            ) {
+                console.set_overflow(overflow);
+            } else {
+                console.set_overflow(Overflow::Wrap);
+            }
+
+            assert_eq!(
+                render_console(rotation, console, &TEXT.repeat(5), &[]),
+                terminal.release().frame(),
+                "{:?} {:?}",
+                rotation,
+                overflow
+            );
+        }
+    }
+}
+
+#[test]
+fn console_stays_within_its_rectangle() {
+    let (origin, size) = ((12, 20), (44, 20));
+    let background = [(0, 0), (11, 20), (56, 39), (12, 40), (127, 63)];
+    let frame = render_console(
+        DisplayRotation::Rotate0,
+        TextConsole::new(origin, size),
+        &"#".repeat(100),
+        &background,
+    );
+
+    for y in 0..64 {
+        for x in 0..128 {
+            let inside = (origin.0..origin.0 + size.0).contains(&(x as u8))
+                && (origin.1..origin.1 + size.1).contains(&(y as u8));
+            if !inside {
+                assert_eq!(
+                    frame.pixel(x, y),
+                    background.contains(&(x as u32, y as u32)),
+                    "({}, {})",
+                    x,
+                    y
+                );
+            }
+        }
+    }
+    // The last row of the rectangle doesn't fit a line of text
+    assert!((12..56).any(|x| (20..28).any(|y| frame.pixel(x, y))));
+    assert!(!(12..56).any(|x| (36..40).any(|y| frame.pixel(x, y))));
+}
+
+#[test]
+fn console_scrolls_within_its_rectangle() {
+    let console = || {
+        let mut console = TextConsole::new((8, 8), (64, 20));
+        console.set_attributes(Attributes::new().underline(true));
+        console
+    };
+    let background = [(8, 0), (8, 28), (72, 8)];
+
+    assert_eq!(
+        render_console(DisplayRotation::Rotate0, console(), "1\n2\n3", &background),
+        render_console(DisplayRotation::Rotate0, console(), "2\n3", &background),
+    );
+
+    let mut console = console();
+    assert_eq!(console.char_dimensions(), (8, 2));
+    console.set_position(7, 1).unwrap();
+    assert!(console.set_position(8, 1).is_err());
+}
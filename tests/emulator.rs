@@ -0,0 +1,330 @@
+use embassy_futures::block_on;
+use ssd1306_async::{
+    command::{AddrMode, Command, FadeMode, HScrollDir, NFrames, Page, VcomhLevel},
+    controller::Controller,
+    emulator::{EmulatedDisplay, Frame},
+    prelude::*,
+    DisplayError, Ssd1306,
+};
+
+fn send(emulator: &mut EmulatedDisplay, commands: &[Command]) {
+    for command in commands {
+        block_on(command.send(emulator)).unwrap();
+    }
+}
+
+fn data(emulator: &mut EmulatedDisplay, bytes: &[u8]) {
+    block_on(emulator.send_data(ssd1306_async::DataFormat::U8(bytes))).unwrap();
+}
+
+#[test]
+fn decodes_init_sequence() {
+    let mut display = Ssd1306::new(
+        EmulatedDisplay::new(),
+        DisplaySize128x32,
+        DisplayRotation::Rotate0,
+    );
+    block_on(display.init()).unwrap();
+
+    let emulator = display.release();
+
+    assert!(emulator.is_on());
+    assert!(emulator.charge_pump());
+    assert!(emulator.segment_remap());
+    assert!(emulator.reverse_com_dir());
+    assert!(!emulator.is_inverted());
+    assert!(!emulator.is_all_on());
+    assert_eq!(emulator.multiplex(), 31);
+    assert_eq!(emulator.com_pin_cfg(), 0x02);
+    assert_eq!(emulator.addr_mode(), AddrMode::Horizontal);
+    assert_eq!(emulator.frame().height(), 32);
+}
+
+#[test]
+fn decodes_controller_init_sequences() {
+    let init = |controller| {
+        let mut display = Ssd1306::new(
+            EmulatedDisplay::new_with_controller(controller),
+            DisplaySize128x32,
+            DisplayRotation::Rotate0,
+        )
+        .with_controller(controller);
+        block_on(display.init()).unwrap();
+        display.release()
+    };
+
+    let emulator = init(Controller::Ssd1309);
+    assert!(!emulator.charge_pump());
+    assert_eq!(emulator.vcomh(), 0x34);
+    assert_eq!(emulator.precharge(), 0x22);
+    assert_eq!(emulator.com_pin_cfg(), 0x02);
+
+    let emulator = init(Controller::Ssd1305);
+    assert!(!emulator.charge_pump());
+    assert_eq!(emulator.vcomh(), 0x34);
+    assert_eq!(emulator.com_pin_cfg(), 0x12);
+    assert_eq!(emulator.frame().width(), 132);
+
+    let emulator = init(Controller::Ssd1315);
+    assert!(emulator.charge_pump());
+    assert_eq!(emulator.vcomh(), 0x20);
+    assert_eq!(emulator.precharge(), 0x21);
+    assert_eq!(emulator.com_pin_cfg(), 0x02);
+
+    let emulator = init(Controller::Sh1106);
+    assert!(emulator.charge_pump());
+    assert_eq!(emulator.com_pin_cfg(), 0x02);
+    assert_eq!(emulator.addr_mode(), AddrMode::Page);
+}
+
+#[test]
+fn decodes_custom_init_config() {
+    let mut display = Ssd1306::new(
+        EmulatedDisplay::new(),
+        DisplaySize128x64,
+        DisplayRotation::Rotate0,
+    )
+    .into_buffered_graphics_mode();
+    let config = InitConfig::new()
+        .oscillator_freq(0xF)
+        .clock_div(1)
+        .charge_pump(false)
+        .vcomh(VcomhLevel::V083)
+        .precharge(3, 4)
+        .com_pin_cfg(false, true)
+        .display_offset(5)
+        .start_line(6);
+    block_on(display.init_with_config(config)).unwrap();
+
+    // Phase 1 of the precharge period is kept when changing the brightness
+    block_on(display.set_brightness(Brightness::DIMMEST)).unwrap();
+
+    let emulator = display.release();
+    assert_eq!(emulator.clock_div(), 0xF1);
+    assert!(!emulator.charge_pump());
+    assert_eq!(emulator.vcomh(), 0x30);
+    assert_eq!(emulator.precharge(), 0x13);
+    assert_eq!(emulator.com_pin_cfg(), 0x22);
+    assert_eq!(emulator.display_offset(), 5);
+    assert_eq!(emulator.start_line(), 6);
+}
+
+#[test]
+fn init_config_truncates_out_of_range_values() {
+    assert_eq!(
+        InitConfig::new()
+            .oscillator_freq(0x1F)
+            .clock_div(0x12)
+            .precharge(0x13, 0x24)
+            .display_offset(70)
+            .start_line(65),
+        InitConfig::new()
+            .oscillator_freq(0xF)
+            .clock_div(2)
+            .precharge(3, 4)
+            .display_offset(6)
+            .start_line(1)
+    );
+}
+
+#[test]
+fn horizontal_addressing_wraps_within_window() {
+    let mut emulator = EmulatedDisplay::new();
+    send(
+        &mut emulator,
+        &[
+            Command::AddressMode(AddrMode::Horizontal),
+            Command::ColumnAddress(10, 12),
+            Command::PageAddress(Page::Page2, Page::Page3),
+        ],
+    );
+    data(&mut emulator, &[1, 2, 3, 4, 5, 6, 7]);
+
+    // The last byte wrapped back to the start of the window
+    assert_eq!(emulator.ram(2)[9..14], [0, 7, 2, 3, 0]);
+    assert_eq!(emulator.ram(3)[9..14], [0, 4, 5, 6, 0]);
+    assert_eq!(emulator.address(), (11, 2));
+}
+
+#[test]
+fn vertical_addressing_walks_down_pages() {
+    let mut emulator = EmulatedDisplay::new();
+    send(
+        &mut emulator,
+        &[
+            Command::AddressMode(AddrMode::Vertical),
+            Command::ColumnAddress(0, 1),
+            Command::PageAddress(Page::Page0, Page::Page1),
+        ],
+    );
+    data(&mut emulator, &[1, 2, 3, 4]);
+
+    let ram = [emulator.ram(0), emulator.ram(1)];
+    assert_eq!([ram[0][0], ram[1][0], ram[0][1], ram[1][1]], [1, 2, 3, 4]);
+}
+
+#[test]
+fn page_addressing_stays_on_page() {
+    let mut emulator = EmulatedDisplay::new();
+    send(
+        &mut emulator,
+        &[Command::PageStart(Page::Page5), Command::ColStart(126)],
+    );
+    data(&mut emulator, &[1, 2, 3]);
+
+    assert_eq!(emulator.ram(5)[126..], [1, 2]);
+    assert_eq!(emulator.ram(5)[0], 3);
+    assert_eq!(emulator.ram(6)[0], 0);
+}
+
+#[test]
+fn renders_remap_and_com_direction() {
+    let mut emulator = EmulatedDisplay::new();
+    send(
+        &mut emulator,
+        &[
+            Command::DisplayOn(true),
+            Command::PageStart(Page::Page0),
+            Command::ColStart(0),
+        ],
+    );
+    data(&mut emulator, &[0x01]);
+
+    // Power-on defaults scan the GDDRAM mirrored in both directions
+    assert!(emulator.pixel(127, 63));
+
+    send(
+        &mut emulator,
+        &[Command::SegmentRemap(true), Command::ReverseComDir(true)],
+    );
+    assert!(emulator.pixel(0, 0));
+
+    send(&mut emulator, &[Command::StartLine(1)]);
+    assert!(emulator.pixel(0, 63));
+    assert!(!emulator.pixel(0, 0));
+}
+
+#[test]
+fn renders_invert_and_all_on() {
+    let mut emulator = EmulatedDisplay::new();
+    send(
+        &mut emulator,
+        &[Command::DisplayOn(true), Command::Invert(true)],
+    );
+    assert!(emulator.pixel(64, 32));
+
+    send(
+        &mut emulator,
+        &[
+            Command::Invert(false),
+            Command::AllOn(true),
+            Command::Multiplex(15),
+        ],
+    );
+    assert_eq!(emulator.frame().height(), 16);
+    assert!(emulator.pixel(0, 15));
+    assert!(!emulator.pixel(0, 16));
+
+    send(&mut emulator, &[Command::DisplayOn(false)]);
+    assert!(!emulator.pixel(0, 0));
+}
+
+#[test]
+fn pbm_round_trip() {
+    let mut frame = Frame::new(3, 2);
+    frame.set_pixel(0, 0, true);
+    frame.set_pixel(2, 1, true);
+
+    let pbm = frame.to_pbm();
+    assert_eq!(pbm, "P1\n3 2\n100\n001\n");
+    assert_eq!(Frame::from_pbm(&pbm), Some(frame));
+    assert_eq!(Frame::from_pbm("P1\n# comment\n2 1\n1"), None);
+}
+
+#[test]
+fn fade_and_zoom() {
+    let mut display = Ssd1306::new(
+        EmulatedDisplay::new(),
+        DisplaySize128x64,
+        DisplayRotation::Rotate0,
+    )
+    .into_buffered_graphics_mode();
+    block_on(display.init()).unwrap();
+    display.set_pixel(5, 10, true);
+    block_on(display.flush()).unwrap();
+
+    block_on(display.set_fade(FadeMode::Blink, 3)).unwrap();
+    assert!(matches!(
+        block_on(display.set_fade(FadeMode::FadeOut, 16)),
+        Err(DisplayError::OutOfBoundsError)
+    ));
+    block_on(display.set_zoom(true)).unwrap();
+
+    let emulator = display.release();
+    assert_eq!(emulator.fade(), 0x33);
+    assert!(emulator.zoom());
+    assert!(!emulator.pixel(5, 10));
+    assert!(emulator.pixel(5, 20));
+    assert!(emulator.pixel(5, 21));
+}
+
+#[test]
+fn fade_and_zoom_not_supported_on_ssd1309() {
+    let mut display = Ssd1306::new(
+        EmulatedDisplay::new_with_controller(Controller::Ssd1309),
+        DisplaySize128x64,
+        DisplayRotation::Rotate0,
+    )
+    .with_controller(Controller::Ssd1309);
+    block_on(display.init()).unwrap();
+
+    assert!(matches!(
+        block_on(display.set_fade(FadeMode::FadeOut, 0)),
+        Err(DisplayError::NotSupported)
+    ));
+    assert!(matches!(
+        block_on(display.set_zoom(true)),
+        Err(DisplayError::NotSupported)
+    ));
+}
+
+#[test]
+fn tracks_display_state() {
+    let mut display = Ssd1306::new(
+        EmulatedDisplay::new(),
+        DisplaySize128x64,
+        DisplayRotation::Rotate0,
+    );
+    assert!(!display.is_on());
+
+    block_on(display.init()).unwrap();
+    assert!(display.is_on());
+    assert!(!display.is_inverted());
+    assert!(!display.is_all_on());
+    assert!(!display.is_mirrored());
+    assert!(!display.is_scrolling());
+    assert_eq!(display.brightness(), Brightness::NORMAL);
+
+    block_on(display.set_invert(true)).unwrap();
+    block_on(display.set_all_on(true)).unwrap();
+    block_on(display.set_mirror(true)).unwrap();
+    block_on(display.set_brightness(Brightness::DIM)).unwrap();
+    block_on(display.start_horizontal_scroll(HScrollDir::LeftToRight, 0..=7, NFrames::F2)).unwrap();
+    assert!(display.is_inverted());
+    assert!(display.is_all_on());
+    assert!(display.is_mirrored());
+    assert!(display.is_scrolling());
+    assert_eq!(display.brightness(), Brightness::DIM);
+
+    block_on(display.stop_scroll()).unwrap();
+    block_on(display.set_rotation(DisplayRotation::Rotate180)).unwrap();
+    block_on(display.set_display_on(false)).unwrap();
+    assert!(!display.is_scrolling());
+    assert!(!display.is_mirrored());
+    assert!(!display.is_on());
+
+    let emulator = display.release();
+    assert!(emulator.is_inverted());
+    assert!(emulator.is_all_on());
+    assert!(!emulator.is_on());
+}
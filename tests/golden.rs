@@ -0,0 +1,509 @@
+//! Golden image tests covering every display size and rotation.
+//!
+//! Each test draws a known pattern through the driver into an emulated display and compares the
+//! rendered panel output against a PBM image in `tests/golden`. Run the tests with
+//! `UPDATE_GOLDEN=1` set to (re)generate the reference images after an intentional change.
+
+use embassy_futures::block_on;
+use ssd1306_async::{
+    controller::Controller,
+    emulator::{EmulatedDisplay, Frame},
+    font::{Font6x8, Font8x16, Font8x8, TerminalFont},
+    mode::{BufferedGraphicsMode, Overflow, TerminalDisplaySize, TextScale},
+    prelude::*,
+    Ssd1306,
+};
+use std::{env, fs, path::PathBuf};
+
+mod common;
+
+use common::ROTATIONS;
+
+/// Compare `frame` against the named golden image, or update the image if `UPDATE_GOLDEN` is set
+fn check_golden(name: &str, frame: &Frame) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(format!("{}.pbm", name));
+
+    if env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, frame.to_pbm()).unwrap();
+        return;
+    }
+
+    let golden = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {}", path.display(), e));
+    let golden = Frame::from_pbm(&golden)
+        .unwrap_or_else(|| panic!("{} is not a valid PBM image", path.display()));
+
+    assert_eq!(
+        frame, &golden,
+        "{} differs from its golden image (left: rendered, right: golden)",
+        name
+    );
+}
+
+/// Logical pattern drawn in buffered graphics mode: a border, a marker in the top left corner
+/// which shows orientation and a diagonal line.
+fn pattern(width: u32, height: u32) -> impl Iterator<Item = (u32, u32)> {
+    let border = (0..width)
+        .flat_map(move |x| [(x, 0), (x, height - 1)])
+        .chain((0..height).flat_map(move |y| [(0, y), (width - 1, y)]));
+    let marker = (2..8).map(|x| (x, 2)).chain((2..5).map(|y| (2, y)));
+    let diagonal = (0..width.min(height)).map(|i| (i, i));
+
+    border.chain(marker).chain(diagonal)
+}
+
+/// A small marker drawn after the first flush, to exercise partial updates
+fn update(width: u32, height: u32) -> impl Iterator<Item = (u32, u32)> {
+    let (cx, cy) = (width / 2, height / 2);
+
+    (cx - 3..cx + 3).flat_map(move |x| (cy - 2..cy + 1).map(move |y| (x, y)))
+}
+
+/// Draw the buffered graphics mode patterns, flushing after each of them
+fn render_buffered<SIZE>(emulator: EmulatedDisplay, size: SIZE, rotation: DisplayRotation) -> Frame
+where
+    SIZE: DisplaySize,
+{
+    let controller = emulator.controller();
+    draw_buffered(
+        Ssd1306::new(emulator, size, rotation)
+            .with_controller(controller)
+            .into_buffered_graphics_mode(),
+    )
+}
+
+/// Draw the buffered graphics mode patterns on a display, flushing after each of them
+fn draw_buffered<SIZE, B>(
+    mut display: Ssd1306<EmulatedDisplay, SIZE, BufferedGraphicsMode<SIZE, B>>,
+) -> Frame
+where
+    SIZE: DisplaySize,
+    B: AsMut<[u8]>,
+{
+    block_on(display.init()).unwrap();
+
+    let (width, height) = display.dimensions();
+    let (width, height) = (width as u32, height as u32);
+
+    for (x, y) in pattern(width, height) {
+        display.set_pixel(x, y, true);
+    }
+    block_on(display.flush()).unwrap();
+
+    for (x, y) in update(width, height) {
+        display.set_pixel(x, y, true);
+    }
+    block_on(display.flush()).unwrap();
+
+    display.release().frame()
+}
+
+/// Print text in terminal mode
+fn render_terminal<SIZE>(
+    emulator: EmulatedDisplay,
+    size: SIZE,
+    rotation: DisplayRotation,
+    overflow: Overflow,
+) -> Frame
+where
+    SIZE: TerminalDisplaySize,
+{
+    render_terminal_text(
+        emulator,
+        size,
+        rotation,
+        Font8x8,
+        overflow,
+        "Rust!\nABCDEFGHIJKLMNOPQRSTUVWXYZ\rab\n\n0123",
+    )
+}
+
+/// Print text in terminal mode using the given font
+fn render_terminal_text<SIZE, F>(
+    emulator: EmulatedDisplay,
+    size: SIZE,
+    rotation: DisplayRotation,
+    font: F,
+    overflow: Overflow,
+    text: &str,
+) -> Frame
+where
+    SIZE: TerminalDisplaySize,
+    F: TerminalFont,
+{
+    let controller = emulator.controller();
+    let mut display = Ssd1306::new(emulator, size, rotation)
+        .with_controller(controller)
+        .into_terminal_mode_with_font(font);
+    display.set_overflow(overflow);
+    block_on(display.init()).unwrap();
+    block_on(display.clear()).unwrap();
+
+    for c in text.chars() {
+        block_on(display.print_char(c)).unwrap();
+    }
+
+    display.release().frame()
+}
+
+fn buffered<SIZE>(size: SIZE, name: &str)
+where
+    SIZE: DisplaySize + Copy,
+{
+    for (rotation, rotation_name) in ROTATIONS {
+        let frame = render_buffered(EmulatedDisplay::new(), size, rotation);
+
+        // A single full flush of the same content must render identically
+        let mut reference =
+            Ssd1306::new(EmulatedDisplay::new(), size, rotation).into_buffered_graphics_mode();
+        block_on(reference.init()).unwrap();
+        let (width, height) = reference.dimensions();
+        let (width, height) = (width as u32, height as u32);
+        for (x, y) in pattern(width, height).chain(update(width, height)) {
+            reference.set_pixel(x, y, true);
+        }
+        block_on(reference.flush()).unwrap();
+        assert_eq!(
+            frame,
+            reference.release().frame(),
+            "Partial flush of {} {} differs from a full flush",
+            name,
+            rotation_name
+        );
+
+        check_golden(&format!("buffered_{}_{}", name, rotation_name), &frame);
+    }
+}
+
+fn terminal<SIZE>(size: SIZE, name: &str)
+where
+    SIZE: TerminalDisplaySize + Copy,
+{
+    for (rotation, rotation_name) in ROTATIONS {
+        let wrapped = render_terminal(EmulatedDisplay::new(), size, rotation, Overflow::Wrap);
+        check_golden(&format!("terminal_{}_{}", name, rotation_name), &wrapped);
+
+        // Text on rotated displays can't be scrolled
+        let scrolled = render_terminal(EmulatedDisplay::new(), size, rotation, Overflow::Scroll);
+        match rotation {
+            DisplayRotation::Rotate0 | DisplayRotation::Rotate180 => check_golden(
+                &format!("terminal_scroll_{}_{}", name, rotation_name),
+                &scrolled,
+            ),
+            DisplayRotation::Rotate90 | DisplayRotation::Rotate270 => {
+                assert_eq!(scrolled, wrapped, "{} {}", name, rotation_name)
+            }
+        }
+    }
+}
+
+macro_rules! golden_tests {
+    ($($size:expr => $buffered:ident, $terminal:ident, $name:literal;)*) => {
+        $(
+            #[test]
+            fn $buffered() {
+                buffered($size, $name);
+            }
+
+            #[test]
+            fn $terminal() {
+                terminal($size, $name);
+            }
+        )*
+    };
+}
+
+golden_tests! {
+    DisplaySize128x64 => buffered_128x64, terminal_128x64, "128x64";
+    DisplaySize128x32 => buffered_128x32, terminal_128x32, "128x32";
+    DisplaySize96x16 => buffered_96x16, terminal_96x16, "96x16";
+    DisplaySize72x40 => buffered_72x40, terminal_72x40, "72x40";
+    DisplaySize64x48 => buffered_64x48, terminal_64x48, "64x48";
+    DisplaySize64x32 => buffered_64x32, terminal_64x32, "64x32";
+    CustomSize::<128, 48> => buffered_128x48, terminal_128x48, "128x48";
+    CustomSize::<80, 48, 24> => buffered_80x48, terminal_80x48, "80x48";
+    CustomSize::<128, 32, 0, 32> => buffered_128x32_y32, terminal_128x32_y32, "128x32_y32";
+}
+
+/// A custom size with the same geometry as a built-in size must render identically
+fn matches_built_in<SIZE, CUSTOM>(size: SIZE, custom: CUSTOM)
+where
+    SIZE: TerminalDisplaySize + Copy,
+    CUSTOM: TerminalDisplaySize + Copy,
+{
+    assert_eq!(CUSTOM::CHAR_NUM, SIZE::CHAR_NUM);
+
+    let com_pin_cfg = |emulator: EmulatedDisplay| emulator.com_pin_cfg();
+    let mut display = Ssd1306::new(EmulatedDisplay::new(), size, DisplayRotation::Rotate0);
+    block_on(display.init()).unwrap();
+    let mut custom_display = Ssd1306::new(EmulatedDisplay::new(), custom, DisplayRotation::Rotate0);
+    block_on(custom_display.init()).unwrap();
+    assert_eq!(
+        com_pin_cfg(custom_display.release()),
+        com_pin_cfg(display.release())
+    );
+
+    for (rotation, rotation_name) in ROTATIONS {
+        assert_eq!(
+            render_buffered(EmulatedDisplay::new(), custom, rotation),
+            render_buffered(EmulatedDisplay::new(), size, rotation),
+            "buffered {}x{} {}",
+            SIZE::WIDTH,
+            SIZE::HEIGHT,
+            rotation_name
+        );
+        assert_eq!(
+            render_terminal(EmulatedDisplay::new(), custom, rotation, Overflow::Scroll),
+            render_terminal(EmulatedDisplay::new(), size, rotation, Overflow::Scroll),
+            "terminal {}x{} {}",
+            SIZE::WIDTH,
+            SIZE::HEIGHT,
+            rotation_name
+        );
+    }
+}
+
+/// A runtime size must render like the built-in size with the same geometry, using either buffer
+fn runtime_matches_built_in<SIZE>(size: SIZE, runtime: RuntimeSize)
+where
+    SIZE: DisplaySize + Copy,
+{
+    for (rotation, rotation_name) in ROTATIONS {
+        let reference = render_buffered(EmulatedDisplay::new(), size, rotation);
+        assert_eq!(
+            render_buffered(EmulatedDisplay::new(), runtime, rotation),
+            reference,
+            "{:?} {}",
+            runtime,
+            rotation_name
+        );
+
+        let mut buffer = vec![0; runtime.buffer_len()];
+        let display = Ssd1306::new(EmulatedDisplay::new(), runtime, rotation)
+            .into_buffered_graphics_mode_with_buffer(&mut buffer[..]);
+        assert_eq!(
+            draw_buffered(display),
+            reference,
+            "{:?} {} with buffer",
+            runtime,
+            rotation_name
+        );
+    }
+}
+
+#[test]
+fn runtime_sizes_match_built_in_sizes() {
+    runtime_matches_built_in(
+        CustomSize::<128, 64>,
+        RuntimeSize::new(128, 64, 0, 0).unwrap(),
+    );
+    runtime_matches_built_in(
+        CustomSize::<96, 16>,
+        RuntimeSize::new(96, 16, 0, 0).unwrap(),
+    );
+    runtime_matches_built_in(
+        CustomSize::<72, 40, 28>,
+        RuntimeSize::new(72, 40, 28, 0).unwrap(),
+    );
+    runtime_matches_built_in(
+        CustomSize::<80, 48, 24>,
+        RuntimeSize::new(80, 48, 24, 0).unwrap(),
+    );
+    runtime_matches_built_in(
+        CustomSize::<128, 32, 0, 32>,
+        RuntimeSize::new(128, 32, 0, 32).unwrap(),
+    );
+}
+
+#[test]
+fn runtime_size_shows_vertical_offset() {
+    let size = RuntimeSize::new(128, 32, 0, 32).unwrap();
+
+    let mut display = Ssd1306::new(EmulatedDisplay::new(), size, DisplayRotation::Rotate0);
+    block_on(display.init()).unwrap();
+    assert_eq!(display.release().start_line(), 32);
+
+    for (rotation, rotation_name) in ROTATIONS {
+        let frame = render_buffered(EmulatedDisplay::new(), size, rotation);
+        check_golden(&format!("buffered_128x32_y32_{}", rotation_name), &frame);
+    }
+}
+
+#[test]
+fn custom_sizes_match_built_in_sizes() {
+    matches_built_in(DisplaySize128x64, CustomSize::<128, 64>);
+    matches_built_in(DisplaySize128x32, CustomSize::<128, 32>);
+    matches_built_in(DisplaySize96x16, CustomSize::<96, 16>);
+    matches_built_in(DisplaySize64x48, CustomSize::<64, 48, 32>);
+    matches_built_in(DisplaySize128x32, CustomSize::<128, 32, 0, 32>);
+}
+
+fn terminal_font<F>(font: F, name: &str)
+where
+    F: TerminalFont + Copy,
+{
+    for (rotation, rotation_name) in ROTATIONS {
+        check_golden(
+            &format!("terminal_{}_128x64_{}", name, rotation_name),
+            &render_terminal_text(
+                EmulatedDisplay::new(),
+                DisplaySize128x64,
+                rotation,
+                font,
+                Overflow::Scroll,
+                "Grüße, señor!\nÀÉÎõ ½ gjpqy_|\n0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+            ),
+        );
+    }
+}
+
+#[test]
+fn terminal_font6x8() {
+    terminal_font(Font6x8, "font6x8");
+}
+
+#[test]
+fn terminal_font8x16() {
+    terminal_font(Font8x16, "font8x16");
+}
+
+#[test]
+fn terminal_attributes() {
+    for (rotation, rotation_name) in ROTATIONS {
+        check_golden(
+            &format!("terminal_attributes_128x64_{}", rotation_name),
+            &render_terminal_text(
+                EmulatedDisplay::new(),
+                DisplaySize128x64,
+                rotation,
+                Font8x8,
+                Overflow::Scroll,
+                "Text \x1b[7mInverse\x1b[27m\n\x1b[4mUnderline\x1b[24m\n\x1b[9mStrike\x1b[m\n\
+                 \x1b[7;4;9mAll\x1b[0m end",
+            ),
+        );
+    }
+}
+
+#[test]
+fn terminal_text_scale() {
+    for (rotation, rotation_name) in ROTATIONS {
+        let mut display =
+            Ssd1306::new(EmulatedDisplay::new(), DisplaySize128x64, rotation).into_terminal_mode();
+        block_on(display.init()).unwrap();
+        block_on(display.clear()).unwrap();
+
+        block_on(display.write_str("Battery")).unwrap();
+        block_on(display.set_text_scale(TextScale::X2)).unwrap();
+        block_on(display.set_position(0, 1)).unwrap();
+        block_on(display.write_str("\x1b[4m87%")).unwrap();
+        block_on(display.set_text_scale(TextScale::X4)).unwrap();
+        block_on(display.set_position(0, 1)).unwrap();
+        block_on(display.write_str("\x1b[0;7m42")).unwrap();
+
+        check_golden(
+            &format!("terminal_text_scale_128x64_{}", rotation_name),
+            &display.release().frame(),
+        );
+    }
+}
+
+#[test]
+fn terminal_text_stretched() {
+    for (scale, scale_name) in [
+        (TextScale::DoubleWidth, "double_width"),
+        (TextScale::DoubleHeight, "double_height"),
+    ] {
+        for (rotation, rotation_name) in ROTATIONS {
+            let mut display = Ssd1306::new(EmulatedDisplay::new(), DisplaySize128x64, rotation)
+                .into_terminal_mode();
+            block_on(display.init()).unwrap();
+            block_on(display.clear()).unwrap();
+
+            block_on(display.set_text_scale(scale)).unwrap();
+            block_on(display.write_str("Wide\n\x1b[4mTall\x1b[0;7m 42")).unwrap();
+
+            check_golden(
+                &format!("terminal_text_{}_128x64_{}", scale_name, rotation_name),
+                &display.release().frame(),
+            );
+        }
+    }
+}
+
+/// Other controllers must show exactly the same image as the SSD1306. 128px wide panels are
+/// centered in wider GDDRAMs.
+fn matches_ssd1306<SIZE>(controller: Controller, size: SIZE)
+where
+    SIZE: TerminalDisplaySize + Copy,
+{
+    let emulator = || EmulatedDisplay::new_with_controller(controller);
+    let offset = controller.column_offset() as usize;
+
+    // The COM pin configuration isn't rendered by the emulator, so it's checked separately
+    let com_pin_cfg = |controller| {
+        let mut display = Ssd1306::new(
+            EmulatedDisplay::new_with_controller(controller),
+            size,
+            DisplayRotation::Rotate0,
+        )
+        .with_controller(controller);
+        block_on(display.init()).unwrap();
+        display.release().com_pin_cfg()
+    };
+    let expected = match controller {
+        Controller::Ssd1305 => 0x12,
+        _ => com_pin_cfg(Controller::Ssd1306),
+    };
+    assert_eq!(
+        com_pin_cfg(controller),
+        expected,
+        "{:?} COM pin configuration",
+        controller
+    );
+
+    for (rotation, rotation_name) in ROTATIONS {
+        let reference = render_buffered(EmulatedDisplay::new(), size, rotation);
+        let frame = render_buffered(emulator(), size, rotation);
+        assert_eq!(frame.width(), controller.columns() as usize);
+        assert_eq!(
+            frame.crop(offset, 0, 128, frame.height()),
+            reference,
+            "{:?} buffered {}",
+            controller,
+            rotation_name
+        );
+
+        let reference = render_terminal(EmulatedDisplay::new(), size, rotation, Overflow::Scroll);
+        let frame = render_terminal(emulator(), size, rotation, Overflow::Scroll);
+        assert_eq!(
+            frame.crop(offset, 0, 128, frame.height()),
+            reference,
+            "{:?} terminal {}",
+            controller,
+            rotation_name
+        );
+    }
+}
+
+macro_rules! controller_tests {
+    ($($controller:ident => $name:ident;)*) => {
+        $(
+            #[test]
+            fn $name() {
+                matches_ssd1306(Controller::$controller, DisplaySize128x64);
+                matches_ssd1306(Controller::$controller, DisplaySize128x32);
+            }
+        )*
+    };
+}
+
+controller_tests! {
+    Sh1106 => sh1106;
+    Ssd1309 => ssd1309;
+    Ssd1305 => ssd1305;
+    Ssd1315 => ssd1315;
+}
@@ -0,0 +1,131 @@
+use core::convert::Infallible;
+use embassy_futures::block_on;
+use embedded_hal::{
+    digital::{self, OutputPin},
+    i2c::{self, ErrorKind, NoAcknowledgeSource, Operation},
+    spi,
+};
+use embedded_hal_async::{i2c::I2c, spi::SpiDevice};
+use ssd1306_async::{command::Command, prelude::*, DataFormat, DisplayError, I2CDisplayInterface};
+use std::{vec, vec::Vec};
+
+/// I2C bus which records writes, or fails with the given error
+#[derive(Default)]
+struct MockI2c {
+    writes: Vec<(u8, Vec<u8>)>,
+    error: Option<ErrorKind>,
+}
+
+#[derive(Debug)]
+struct MockError<K>(K);
+
+impl i2c::Error for MockError<ErrorKind> {
+    fn kind(&self) -> ErrorKind {
+        self.0
+    }
+}
+
+impl spi::Error for MockError<spi::ErrorKind> {
+    fn kind(&self) -> spi::ErrorKind {
+        self.0
+    }
+}
+
+impl i2c::ErrorType for MockI2c {
+    type Error = MockError<ErrorKind>;
+}
+
+impl I2c for MockI2c {
+    async fn transaction(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        if let Some(kind) = self.error {
+            return Err(MockError(kind));
+        }
+
+        for op in operations {
+            if let Operation::Write(bytes) = op {
+                self.writes.push((address, bytes.to_vec()));
+            }
+        }
+        Ok(())
+    }
+}
+
+struct FailingSpi;
+
+impl spi::ErrorType for FailingSpi {
+    type Error = MockError<spi::ErrorKind>;
+}
+
+impl SpiDevice for FailingSpi {
+    async fn transaction(
+        &mut self,
+        _operations: &mut [spi::Operation<'_, u8>],
+    ) -> Result<(), Self::Error> {
+        Err(MockError(spi::ErrorKind::Overrun))
+    }
+}
+
+struct DcPin;
+
+impl digital::ErrorType for DcPin {
+    type Error = Infallible;
+}
+
+impl OutputPin for DcPin {
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[test]
+fn i2c_error_kind_is_kept() {
+    let mut interface = I2CDisplayInterface::new(MockI2c {
+        error: Some(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)),
+        ..MockI2c::default()
+    });
+
+    let err = block_on(Command::DisplayOn(true).send(&mut interface)).unwrap_err();
+
+    assert!(matches!(
+        err,
+        DisplayError::I2cError(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address))
+    ));
+    assert_eq!(
+        i2c::Error::kind(&err),
+        ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)
+    );
+}
+
+#[test]
+fn spi_error_kind_is_kept() {
+    let mut interface = SPIInterface::new(FailingSpi, DcPin);
+
+    let err = block_on(interface.send_data(DataFormat::U8(&[1, 2, 3]))).unwrap_err();
+
+    assert!(matches!(
+        err,
+        DisplayError::SpiError(spi::ErrorKind::Overrun)
+    ));
+    assert_eq!(spi::Error::kind(&err), spi::ErrorKind::Overrun);
+    assert_eq!(i2c::Error::kind(&err), ErrorKind::Other);
+}
+
+#[test]
+fn i2c_iterator_data_is_chunked() {
+    let mut interface = I2CDisplayInterface::new(MockI2c::default());
+
+    block_on(interface.send_data(DataFormat::U8Iter(&mut (0..40u8)))).unwrap();
+
+    let i2c = interface.release();
+    let chunk = |data: core::ops::Range<u8>| (0x3C, [0x40].into_iter().chain(data).collect());
+    let expected: Vec<(u8, Vec<u8>)> = vec![chunk(0..16), chunk(16..32), chunk(32..40)];
+    assert_eq!(i2c.writes, expected);
+}
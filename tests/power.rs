@@ -0,0 +1,237 @@
+use core::{cell::RefCell, convert::Infallible};
+use embassy_futures::block_on;
+use embedded_hal::digital::{ErrorType, OutputPin};
+use embedded_hal_async::delay::DelayNs;
+use ssd1306_async::{
+    controller::Controller, emulator::EmulatedDisplay, prelude::*, DataFormat, DisplayError,
+    Ssd1306,
+};
+use std::{rc::Rc, vec::Vec};
+
+#[derive(Debug, PartialEq)]
+enum Event {
+    Rst(bool),
+    Vcc(bool),
+    DelayMs(u32),
+    DisplayOn(bool),
+    ChargePump(bool),
+    /// DC-DC control or master configuration, with its argument
+    SetDcDc(u8),
+}
+
+type Log = Rc<RefCell<Vec<Event>>>;
+
+/// Records the power related commands sent to an emulated display
+struct Recorder {
+    display: EmulatedDisplay,
+    log: Log,
+}
+
+impl WriteOnlyDataCommand for Recorder {
+    type Error = DisplayError;
+
+    async fn send_commands(&mut self, cmds: DataFormat<'_>) -> Result<(), Self::Error> {
+        if let DataFormat::U8(bytes) = &cmds {
+            match bytes {
+                [0xAE] | [0xAF] => self
+                    .log
+                    .borrow_mut()
+                    .push(Event::DisplayOn(bytes[0] & 1 != 0)),
+                [0x8D, arg] => self
+                    .log
+                    .borrow_mut()
+                    .push(Event::ChargePump(arg & 0x04 != 0)),
+                [0xAD, arg] => self.log.borrow_mut().push(Event::SetDcDc(*arg)),
+                _ => {}
+            }
+        }
+        self.display.send_commands(cmds).await
+    }
+
+    async fn send_data(&mut self, buf: DataFormat<'_>) -> Result<(), Self::Error> {
+        self.display.send_data(buf).await
+    }
+}
+
+struct Pin {
+    log: Log,
+    event: fn(bool) -> Event,
+}
+
+impl ErrorType for Pin {
+    type Error = Infallible;
+}
+
+impl OutputPin for Pin {
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.log.borrow_mut().push((self.event)(false));
+        Ok(())
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.log.borrow_mut().push((self.event)(true));
+        Ok(())
+    }
+}
+
+struct Delay {
+    log: Log,
+}
+
+impl DelayNs for Delay {
+    async fn delay_ns(&mut self, ns: u32) {
+        self.log.borrow_mut().push(Event::DelayMs(ns / 1_000_000));
+    }
+
+    async fn delay_ms(&mut self, ms: u32) {
+        self.log.borrow_mut().push(Event::DelayMs(ms));
+    }
+}
+
+fn setup() -> (
+    Ssd1306<Recorder, DisplaySize128x64, ssd1306_async::mode::BasicMode>,
+    Pin,
+    Pin,
+    Delay,
+    Log,
+) {
+    let log = Log::default();
+    let display = Ssd1306::new(
+        Recorder {
+            display: EmulatedDisplay::new(),
+            log: log.clone(),
+        },
+        DisplaySize128x64,
+        DisplayRotation::Rotate0,
+    );
+    let rst = Pin {
+        log: log.clone(),
+        event: Event::Rst,
+    };
+    let vcc = Pin {
+        log: log.clone(),
+        event: Event::Vcc,
+    };
+    let delay = Delay { log: log.clone() };
+
+    (display, rst, vcc, delay, log)
+}
+
+#[test]
+fn reset_any_interface() {
+    let (mut display, mut rst, _, mut delay, log) = setup();
+    block_on(display.reset(&mut rst, &mut delay)).unwrap();
+
+    assert_eq!(
+        *log.borrow(),
+        [
+            Event::Rst(true),
+            Event::DelayMs(1),
+            Event::Rst(false),
+            Event::DelayMs(10),
+            Event::Rst(true),
+        ]
+    );
+}
+
+#[test]
+fn power_on_sequence() {
+    let (mut display, mut rst, mut vcc, mut delay, log) = setup();
+    block_on(display.power_on(&mut rst, Some(&mut vcc), &mut delay)).unwrap();
+
+    assert_eq!(
+        *log.borrow(),
+        [
+            Event::Rst(true),
+            Event::DelayMs(1),
+            Event::Rst(false),
+            Event::DelayMs(10),
+            Event::Rst(true),
+            Event::Vcc(true),
+            Event::DelayMs(100),
+            Event::DisplayOn(false),
+            Event::ChargePump(true),
+            Event::DisplayOn(true),
+        ]
+    );
+    assert!(display.is_on());
+    assert!(display.release().display.is_on());
+}
+
+#[test]
+fn power_off_sequence() {
+    let (mut display, mut rst, mut vcc, mut delay, log) = setup();
+    block_on(display.power_on(&mut rst, None::<&mut Pin>, &mut delay)).unwrap();
+    log.borrow_mut().clear();
+
+    block_on(display.power_off(Some(&mut vcc), &mut delay)).unwrap();
+
+    assert_eq!(
+        *log.borrow(),
+        [
+            Event::DisplayOn(false),
+            Event::ChargePump(false),
+            Event::Vcc(false),
+            Event::DelayMs(100),
+        ]
+    );
+    assert!(!display.is_on());
+
+    let emulator = display.release().display;
+    assert!(!emulator.is_on());
+    assert!(!emulator.charge_pump());
+}
+
+#[test]
+fn power_off_without_charge_pump() {
+    let (display, _, mut vcc, mut delay, log) = setup();
+    let mut display = display.with_controller(Controller::Ssd1309);
+
+    block_on(display.power_off(Some(&mut vcc), &mut delay)).unwrap();
+    assert_eq!(
+        *log.borrow(),
+        [
+            Event::DisplayOn(false),
+            Event::Vcc(false),
+            Event::DelayMs(100)
+        ]
+    );
+}
+
+#[test]
+fn power_off_controller_supplies() {
+    let power_off = |controller| {
+        let (display, _, mut vcc, mut delay, log) = setup();
+        let mut display = display.with_controller(controller);
+        block_on(display.power_off(Some(&mut vcc), &mut delay)).unwrap();
+        log.take()
+    };
+
+    // The SSD1305 master configuration doesn't switch a supply off, so it isn't sent
+    assert_eq!(
+        power_off(Controller::Ssd1305),
+        [
+            Event::DisplayOn(false),
+            Event::Vcc(false),
+            Event::DelayMs(100)
+        ]
+    );
+    assert_eq!(
+        power_off(Controller::Sh1106),
+        [
+            Event::DisplayOn(false),
+            Event::SetDcDc(0x8A),
+            Event::Vcc(false),
+            Event::DelayMs(100)
+        ]
+    );
+    assert_eq!(
+        power_off(Controller::Ssd1315),
+        [
+            Event::DisplayOn(false),
+            Event::ChargePump(false),
+            Event::Vcc(false),
+            Event::DelayMs(100)
+        ]
+    );
+}
@@ -0,0 +1,226 @@
+use embassy_futures::block_on;
+use ssd1306_async::{
+    command::{HScrollDir, NFrames, VHScrollDir},
+    controller::Controller,
+    emulator::EmulatedDisplay,
+    font::Font8x8,
+    mode::{TerminalCell, TerminalMode},
+    prelude::*,
+    DisplayError, Ssd1306,
+};
+
+#[test]
+fn horizontal_scroll() {
+    let mut display = Ssd1306::new(
+        EmulatedDisplay::new(),
+        DisplaySize128x32,
+        DisplayRotation::Rotate0,
+    );
+    block_on(display.init()).unwrap();
+    block_on(display.start_horizontal_scroll(HScrollDir::RightToLeft, 1..=3, NFrames::F2)).unwrap();
+
+    let emulator = display.release();
+    assert!(emulator.scroll_enabled());
+    assert_eq!(emulator.scroll_setup(), [0x27, 0, 1, 0b111, 3, 0, 0xFF]);
+}
+
+#[test]
+fn diagonal_scroll() {
+    let mut display = Ssd1306::new(
+        EmulatedDisplay::new(),
+        DisplaySize128x64,
+        DisplayRotation::Rotate0,
+    );
+    block_on(display.init()).unwrap();
+    block_on(display.set_vertical_scroll_area(8, 48)).unwrap();
+    block_on(display.start_diagonal_scroll(VHScrollDir::VerticalLeft, 0..=7, NFrames::F5, 1))
+        .unwrap();
+
+    let emulator = display.release();
+    assert!(emulator.scroll_enabled());
+    assert_eq!(emulator.vertical_scroll_area(), (8, 48));
+    assert_eq!(emulator.scroll_setup(), [0x2A, 0, 0, 0, 7, 1]);
+}
+
+#[test]
+fn rejects_invalid_scroll_settings() {
+    let mut display = Ssd1306::new(
+        EmulatedDisplay::new(),
+        DisplaySize128x32,
+        DisplayRotation::Rotate0,
+    );
+    block_on(display.init()).unwrap();
+
+    assert!(matches!(
+        block_on(display.start_horizontal_scroll(HScrollDir::LeftToRight, 2..=4, NFrames::F2)),
+        Err(DisplayError::OutOfBoundsError)
+    ));
+    #[allow(clippy::reversed_empty_ranges)]
+    let empty = 3..=2;
+    assert!(matches!(
+        block_on(display.start_horizontal_scroll(HScrollDir::LeftToRight, empty, NFrames::F2)),
+        Err(DisplayError::OutOfBoundsError)
+    ));
+    assert!(matches!(
+        block_on(display.start_diagonal_scroll(VHScrollDir::VerticalRight, 0..=3, NFrames::F2, 32)),
+        Err(DisplayError::OutOfBoundsError)
+    ));
+    assert!(matches!(
+        block_on(display.set_vertical_scroll_area(16, 17)),
+        Err(DisplayError::OutOfBoundsError)
+    ));
+
+    assert!(!display.release().scroll_enabled());
+}
+
+#[test]
+fn scrolling_not_supported_on_sh1106() {
+    let mut display = Ssd1306::new(
+        EmulatedDisplay::new_with_controller(Controller::Sh1106),
+        DisplaySize128x64,
+        DisplayRotation::Rotate0,
+    )
+    .with_controller(Controller::Sh1106);
+    block_on(display.init()).unwrap();
+
+    assert!(matches!(
+        block_on(display.start_horizontal_scroll(HScrollDir::LeftToRight, 0..=7, NFrames::F2)),
+        Err(DisplayError::NotSupported)
+    ));
+    assert!(matches!(
+        block_on(display.stop_scroll()),
+        Err(DisplayError::NotSupported)
+    ));
+}
+
+#[test]
+fn stop_scroll_rewrites_display_ram() {
+    let mut display = Ssd1306::new(
+        EmulatedDisplay::new(),
+        DisplaySize128x64,
+        DisplayRotation::Rotate0,
+    )
+    .into_buffered_graphics_mode();
+    block_on(display.init()).unwrap();
+    for i in 0..64 {
+        display.set_pixel(i, i, true);
+    }
+    block_on(display.flush()).unwrap();
+
+    let expected = display.release().frame();
+
+    let mut display = Ssd1306::new(
+        EmulatedDisplay::new(),
+        DisplaySize128x64,
+        DisplayRotation::Rotate0,
+    )
+    .into_buffered_graphics_mode();
+    block_on(display.init()).unwrap();
+    for i in 0..64 {
+        display.set_pixel(i, i, true);
+    }
+    block_on(display.flush()).unwrap();
+    block_on(display.start_horizontal_scroll(HScrollDir::LeftToRight, 0..=7, NFrames::F2)).unwrap();
+
+    // Stand in for the display RAM contents moved by scrolling
+    block_on(display.set_draw_area((0, 0), (128, 64))).unwrap();
+    block_on(display.draw(&[0xAA; 1024])).unwrap();
+
+    block_on(display.stop_scroll()).unwrap();
+
+    let emulator = display.release();
+    assert!(!emulator.scroll_enabled());
+    assert_eq!(emulator.frame(), expected);
+}
+
+/// Print text to a terminal keeping the contents of `cells`
+fn terminal<C>(cells: C) -> Ssd1306<EmulatedDisplay, DisplaySize128x64, TerminalMode<Font8x8, C>>
+where
+    C: AsMut<[TerminalCell]>,
+{
+    let mut display = Ssd1306::new(
+        EmulatedDisplay::new(),
+        DisplaySize128x64,
+        DisplayRotation::Rotate0,
+    )
+    .into_terminal_mode()
+    .with_cells(cells);
+    block_on(display.init()).unwrap();
+    block_on(display.write_str(&"Hello\n".repeat(9))).unwrap();
+    block_on(display.write_str("\x1b[7mworld")).unwrap();
+    display
+}
+
+/// Stop scrolling after garbling the display RAM like scrolling does
+fn stop_scroll<C>(
+    mut display: Ssd1306<EmulatedDisplay, DisplaySize128x64, TerminalMode<Font8x8, C>>,
+) -> Ssd1306<EmulatedDisplay, DisplaySize128x64, TerminalMode<Font8x8, C>>
+where
+    C: AsMut<[TerminalCell]>,
+{
+    block_on(display.start_horizontal_scroll(HScrollDir::LeftToRight, 0..=7, NFrames::F2)).unwrap();
+    block_on(display.set_draw_area((0, 0), (128, 64))).unwrap();
+    block_on(display.draw(&[0xAA; 1024])).unwrap();
+    block_on(display.stop_scroll()).unwrap();
+    display
+}
+
+#[test]
+fn terminal_redraws_text_when_scrolling_stops() {
+    let expected = terminal([TerminalCell::BLANK; 128]);
+    let position = expected.position().unwrap();
+
+    let display = stop_scroll(terminal([TerminalCell::BLANK; 128]));
+    assert_eq!(display.position().unwrap(), position);
+    let emulator = display.release();
+    assert!(!emulator.scroll_enabled());
+    assert_eq!(emulator.frame(), expected.release().frame());
+}
+
+#[test]
+fn terminal_clears_when_scrolling_stops_without_cells() {
+    let display = stop_scroll(terminal([]));
+    assert_eq!(display.position().unwrap(), (0, 0));
+    let emulator = display.release();
+    assert!(!emulator.scroll_enabled());
+    assert_eq!(
+        emulator.frame(),
+        Ssd1306::new(
+            EmulatedDisplay::new(),
+            DisplaySize128x64,
+            DisplayRotation::Rotate0
+        )
+        .release()
+        .frame()
+    );
+}
+
+#[test]
+fn scroll_area_follows_vertical_offset() {
+    let mut display = Ssd1306::new(
+        EmulatedDisplay::new(),
+        CustomSize::<128, 32, 0, 32>,
+        DisplayRotation::Rotate0,
+    );
+    block_on(display.init()).unwrap();
+
+    // The whole panel scrolls until the scroll area is limited
+    assert!(matches!(
+        block_on(display.start_diagonal_scroll(VHScrollDir::VerticalRight, 0..=3, NFrames::F2, 32)),
+        Err(DisplayError::OutOfBoundsError)
+    ));
+    block_on(display.start_diagonal_scroll(VHScrollDir::VerticalRight, 0..=3, NFrames::F2, 31))
+        .unwrap();
+
+    block_on(display.set_vertical_scroll_area(8, 16)).unwrap();
+    assert!(matches!(
+        block_on(display.start_diagonal_scroll(VHScrollDir::VerticalRight, 0..=3, NFrames::F2, 16)),
+        Err(DisplayError::OutOfBoundsError)
+    ));
+    block_on(display.start_diagonal_scroll(VHScrollDir::VerticalRight, 0..=3, NFrames::F2, 15))
+        .unwrap();
+
+    let emulator = display.release();
+    assert_eq!(emulator.vertical_scroll_area(), (40, 16));
+    assert_eq!(emulator.scroll_setup(), [0x29, 0, 4, 7, 7, 15]);
+}
@@ -0,0 +1,737 @@
+use core::fmt::Write as _;
+use embassy_futures::block_on;
+use embedded_io_async::Write;
+use ssd1306_async::{
+    emulator::{EmulatedDisplay, Frame},
+    font::{Font5x8, Font6x8, Font8x16, Font8x8, TerminalFont},
+    mode::{
+        char_num, Attributes, CursorStyle, Overflow, TerminalCell, TerminalDisplaySize,
+        TerminalMode, TerminalModeError, TextScale,
+    },
+    prelude::*,
+    DataFormat, DisplayError, Ssd1306,
+};
+use std::{cell::Cell, rc::Rc, string::String};
+
+/// Display interface which counts the data transfers sent to an emulated display
+#[derive(Default)]
+struct CountingInterface {
+    display: EmulatedDisplay,
+    data_transfers: Rc<Cell<usize>>,
+}
+
+impl WriteOnlyDataCommand for CountingInterface {
+    type Error = DisplayError;
+
+    async fn send_commands(&mut self, cmd: DataFormat<'_>) -> Result<(), Self::Error> {
+        self.display.send_commands(cmd).await
+    }
+
+    async fn send_data(&mut self, buf: DataFormat<'_>) -> Result<(), Self::Error> {
+        self.data_transfers.set(self.data_transfers.get() + 1);
+        self.display.send_data(buf).await
+    }
+}
+
+fn terminal<SIZE: TerminalDisplaySize>(
+    size: SIZE,
+    rotation: DisplayRotation,
+) -> Ssd1306<CountingInterface, SIZE, TerminalMode> {
+    let mut display =
+        Ssd1306::new(CountingInterface::default(), size, rotation).into_terminal_mode();
+    block_on(display.init()).unwrap();
+    display
+}
+
+#[test]
+fn write_str_matches_print_char() {
+    let text = "Hello,\nworld! This line is long enough to wrap\r>";
+
+    for rotation in [DisplayRotation::Rotate0, DisplayRotation::Rotate90] {
+        let mut expected = terminal(DisplaySize128x32, rotation);
+        for c in text.chars() {
+            block_on(expected.print_char(c)).unwrap();
+        }
+
+        let mut display = terminal(DisplaySize128x32, rotation);
+        block_on(display.write_str(text)).unwrap();
+
+        assert_eq!(display.position().unwrap(), expected.position().unwrap());
+        assert_eq!(
+            display.release().display.frame(),
+            expected.release().display.frame()
+        );
+    }
+}
+
+#[test]
+fn write_str_sends_lines_in_one_transfer() {
+    let interface = CountingInterface::default();
+    let transfers = interface.data_transfers.clone();
+    let mut display =
+        Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0).into_terminal_mode();
+    block_on(display.init()).unwrap();
+    transfers.set(0);
+
+    // 16 characters fit on a line, so this wraps once
+    block_on(display.write_str("abc\r\n0123456789ABCDEFGH")).unwrap();
+
+    assert_eq!(transfers.get(), 3);
+    assert_eq!(display.position().unwrap(), (2, 2));
+}
+
+#[test]
+fn write_fmt_prints_formatted_text() {
+    let mut display = terminal(DisplaySize128x64, DisplayRotation::Rotate0);
+    let (name, value) = ("Value", 12345);
+    let long = "x".repeat(100);
+
+    block_on(async { write!(display, "{}: {}\n{}", name, value, long).await }).unwrap();
+
+    let mut text = String::new();
+    write!(text, "{}: {}\n{}", name, value, long).unwrap();
+    let mut expected = terminal(DisplaySize128x64, DisplayRotation::Rotate0);
+    block_on(expected.write_str(&text)).unwrap();
+
+    assert_eq!(
+        display.release().display.frame(),
+        expected.release().display.frame()
+    );
+}
+
+/// Formats differently the second time, like a value changed by an interrupt
+struct Changing(Cell<bool>);
+
+impl core::fmt::Display for Changing {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.0.replace(true) {
+            f.write_str(&"é".repeat(20))
+        } else {
+            write!(f, "{}é", "a".repeat(31))
+        }
+    }
+}
+
+#[test]
+fn write_fmt_tolerates_changing_output() {
+    let mut display = terminal(DisplaySize128x64, DisplayRotation::Rotate0);
+    let value = Changing(Cell::new(false));
+
+    block_on(async { write!(display, "{}", value).await }).unwrap();
+}
+
+#[test]
+fn write_propagates_errors() {
+    let mut display = Ssd1306::new(
+        EmulatedDisplay::new(),
+        DisplaySize128x32,
+        DisplayRotation::Rotate0,
+    )
+    .into_terminal_mode();
+
+    assert!(matches!(
+        block_on(display.write_str("uninitialized")),
+        Err(TerminalModeError::Uninitialized)
+    ));
+    assert!(matches!(
+        block_on(display.write_all(b"uninitialized")),
+        Err(TerminalModeError::Uninitialized)
+    ));
+}
+
+#[test]
+fn io_write_decodes_utf8() {
+    let mut display = terminal(DisplaySize128x32, DisplayRotation::Rotate0);
+
+    // The second byte of "é" is missing, so it's printed as an unknown character
+    let written = block_on(display.write(b"ab\xC3")).unwrap();
+    assert_eq!(written, 2);
+    block_on(display.write_all(b"\xC3\xFFcd")).unwrap();
+
+    assert_eq!(display.position().unwrap(), (6, 0));
+
+    let mut display = terminal(DisplaySize128x32, DisplayRotation::Rotate0);
+
+    // "é" split across two writes is a single character
+    assert_eq!(block_on(display.write(b"\xC3")).unwrap(), 1);
+    assert_eq!(block_on(display.write(b"\xA9")).unwrap(), 1);
+    assert_eq!(display.position().unwrap(), (1, 0));
+
+    // "€" split across three writes, followed by more text
+    block_on(display.write_all(b"\xE2")).unwrap();
+    block_on(display.write_all(b"\x82")).unwrap();
+    block_on(display.write_all(b"\xACab")).unwrap();
+    assert_eq!(display.position().unwrap(), (4, 0));
+
+    // A sequence that doesn't continue is a single unknown character, and the next byte is kept
+    block_on(display.write_all(b"\xE2\x82")).unwrap();
+    block_on(display.write_all(b"c")).unwrap();
+    assert_eq!(display.position().unwrap(), (6, 0));
+
+    let mut expected = terminal(DisplaySize128x32, DisplayRotation::Rotate0);
+    block_on(expected.write_str("é€ab\u{FFFD}c")).unwrap();
+    assert_eq!(
+        display.release().display.frame(),
+        expected.release().display.frame()
+    );
+}
+
+/// Get the terminal dimensions using a font, checking that the last column can be used
+fn dimensions<F: TerminalFont>(rotation: DisplayRotation, font: F) -> (u8, u8) {
+    let mut display = Ssd1306::new(EmulatedDisplay::new(), DisplaySize128x64, rotation)
+        .into_terminal_mode_with_font(font);
+    block_on(display.init()).unwrap();
+    block_on(display.set_position(display.char_dimensions().0 - 1, 0)).unwrap();
+    display.char_dimensions()
+}
+
+#[test]
+fn fonts_set_terminal_dimensions() {
+    assert_eq!(dimensions(DisplayRotation::Rotate0, Font5x8), (25, 8));
+    assert_eq!(dimensions(DisplayRotation::Rotate0, Font6x8), (21, 8));
+    assert_eq!(dimensions(DisplayRotation::Rotate90, Font6x8), (8, 16));
+
+    let mut display = Ssd1306::new(
+        EmulatedDisplay::new(),
+        DisplaySize128x64,
+        DisplayRotation::Rotate0,
+    )
+    .into_terminal_mode_with_font(Font8x16);
+    block_on(display.init()).unwrap();
+    assert_eq!(display.char_dimensions(), (16, 4));
+    assert!(matches!(
+        block_on(display.set_position(0, 4)),
+        Err(TerminalModeError::OutOfBounds)
+    ));
+}
+
+#[test]
+fn unknown_characters_are_drawn_as_question_marks() {
+    let render = |text| {
+        let mut display = Ssd1306::new(
+            EmulatedDisplay::new(),
+            DisplaySize128x32,
+            DisplayRotation::Rotate0,
+        )
+        .into_terminal_mode_with_font(Font6x8);
+        block_on(display.init()).unwrap();
+        block_on(display.write_str(text)).unwrap();
+        display.release().frame()
+    };
+
+    assert_eq!(render("a\u{1F600}é"), render("a?é"));
+}
+
+#[test]
+fn text_scrolls_past_last_line() {
+    let mut display = terminal(DisplaySize128x32, DisplayRotation::Rotate0);
+    assert_eq!(display.overflow(), Overflow::Scroll);
+
+    block_on(display.write_str("top\n1\n2\n3")).unwrap();
+    assert_eq!(display.release().display.start_line(), 0);
+
+    let mut display = terminal(DisplaySize128x32, DisplayRotation::Rotate0);
+    block_on(display.write_str("top\n1\n2\n3\n4")).unwrap();
+    assert_eq!(display.position().unwrap(), (1, 3));
+
+    let mut expected = terminal(DisplaySize128x32, DisplayRotation::Rotate0);
+    block_on(expected.write_str("1\n2\n3\n4")).unwrap();
+    assert_eq!(
+        display.release().display.frame(),
+        expected.release().display.frame()
+    );
+}
+
+#[test]
+fn scrolled_lines_are_cleared() {
+    let mut display = terminal(DisplaySize128x64, DisplayRotation::Rotate0);
+    // Fill the whole display, then scroll it around the display RAM more than once
+    block_on(display.write_str(&"#".repeat(16 * 8 * 3))).unwrap();
+    block_on(display.write_str("\nend")).unwrap();
+
+    let mut expected = terminal(DisplaySize128x64, DisplayRotation::Rotate0);
+    block_on(expected.write_str(&"#".repeat(16 * 6))).unwrap();
+    block_on(expected.write_str("\nend")).unwrap();
+
+    let emulator = display.release().display;
+    assert_eq!(emulator.start_line(), 16);
+    assert_eq!(emulator.frame(), expected.release().display.frame());
+}
+
+#[test]
+fn wrap_overflow_restarts_at_top() {
+    let mut display = terminal(DisplaySize128x32, DisplayRotation::Rotate0);
+    display.set_overflow(Overflow::Wrap);
+
+    block_on(display.write_str("top\n1\n2\n3\n4")).unwrap();
+    assert_eq!(display.position().unwrap(), (1, 0));
+    assert_eq!(display.release().display.start_line(), 0);
+}
+
+#[test]
+fn clear_resets_scrolling() {
+    let mut display = terminal(DisplaySize128x32, DisplayRotation::Rotate0);
+    block_on(display.write_str("top\n1\n2\n3\n4\n5")).unwrap();
+    block_on(display.clear()).unwrap();
+    block_on(display.write_str("top")).unwrap();
+
+    let expected = {
+        let mut display = terminal(DisplaySize128x32, DisplayRotation::Rotate0);
+        block_on(display.write_str("top")).unwrap();
+        display.release().display
+    };
+
+    let emulator = display.release().display;
+    assert_eq!(emulator.start_line(), 0);
+    assert_eq!(emulator.frame(), expected.frame());
+}
+
+/// Print text on a 16x4 character display and get the resulting frame
+fn render(text: &str) -> Frame {
+    let mut display = terminal(DisplaySize128x32, DisplayRotation::Rotate0);
+    block_on(display.write_str(text)).unwrap();
+    display.release().display.frame()
+}
+
+#[test]
+fn cursor_movement_sequences() {
+    let mut display = terminal(DisplaySize128x32, DisplayRotation::Rotate0);
+    let mut position = |text| {
+        block_on(display.write_str(text)).unwrap();
+        display.position().unwrap()
+    };
+
+    assert_eq!(position("\x1b[2;5H"), (4, 1));
+    assert_eq!(position("\x1b[A"), (4, 0));
+    assert_eq!(position("\x1b[2B"), (4, 2));
+    assert_eq!(position("\x1b[3C"), (7, 2));
+    assert_eq!(position("\x1b[0D"), (6, 2));
+    assert_eq!(position("\x1b[H"), (0, 0));
+    assert_eq!(position("\x1b[99;99f"), (15, 3));
+    assert_eq!(position("\x1b[99A\x1b[99D"), (0, 0));
+    assert_eq!(position("\x1b[3;3H\x1b7\x1b[H\x1b8"), (2, 2));
+    assert_eq!(position("\x1b[s\x1b[4;1H\x1b[u"), (2, 2));
+}
+
+#[test]
+fn tab_backspace_and_form_feed() {
+    let mut display = terminal(DisplaySize128x32, DisplayRotation::Rotate0);
+    let mut position = |text| {
+        block_on(display.write_str(text)).unwrap();
+        display.position().unwrap()
+    };
+
+    assert_eq!(position("a\t"), (8, 0));
+    assert_eq!(position("\t"), (15, 0));
+    assert_eq!(position("\t"), (15, 0));
+    assert_eq!(position("\x08\x08"), (13, 0));
+    assert_eq!(position("\r\x08"), (0, 0));
+
+    assert_eq!(render("abc\x08\x08X"), render("aXc"));
+    assert_eq!(render("text\nmore\x0cnew"), render("new"));
+}
+
+#[test]
+fn erase_sequences() {
+    let lines = "0123456789abcdef\
+                 0123456789abcdef\
+                 0123456789abcdef\
+                 0123456789abcde";
+
+    assert_eq!(
+        render(&format!("{}\x1b[2;5H\x1b[K", lines)),
+        render("0123456789abcdef0123\n0123456789abcdef0123456789abcde")
+    );
+    assert_eq!(
+        render(&format!("{}\x1b[2;5H\x1b[1K", lines)),
+        render("0123456789abcdef\x1b[5C56789abcdef0123456789abcdef0123456789abcde")
+    );
+    assert_eq!(
+        render(&format!("{}\x1b[2;5H\x1b[2K", lines)),
+        render("0123456789abcdef\n0123456789abcdef0123456789abcde")
+    );
+    assert_eq!(
+        render(&format!("{}\x1b[2;5H\x1b[J", lines)),
+        render("0123456789abcdef0123")
+    );
+    assert_eq!(
+        render(&format!("{}\x1b[2;5H\x1b[1J", lines)),
+        render("\n\x1b[5C56789abcdef0123456789abcdef0123456789abcde")
+    );
+    assert_eq!(
+        render(&format!("{}\x1b[2;5H\x1b[2JX", lines)),
+        render("\n    X")
+    );
+}
+
+#[test]
+fn erase_keeps_cursor_position() {
+    let mut display = terminal(DisplaySize128x64, DisplayRotation::Rotate90);
+    block_on(display.write_str("abcdef\x1b[3D\x1b[K")).unwrap();
+    assert_eq!(display.position().unwrap(), (3, 0));
+    block_on(display.write_str("X")).unwrap();
+
+    let mut expected = terminal(DisplaySize128x64, DisplayRotation::Rotate90);
+    block_on(expected.write_str("abcX")).unwrap();
+
+    assert_eq!(
+        display.release().display.frame(),
+        expected.release().display.frame()
+    );
+}
+
+#[test]
+fn reverse_video_inverts_glyphs() {
+    let mut display = terminal(DisplaySize128x32, DisplayRotation::Rotate0);
+    block_on(display.write_str("\x1b[7mA\x1b[27mA\x1b[7m\x1b7\x1b[m\x1b8A\x1b[0mA")).unwrap();
+
+    let glyph = Font8x8.glyph('A').unwrap();
+    let inverted = glyph.iter().map(|byte| !byte).collect::<Vec<_>>();
+    let emulator = display.release().display;
+    let ram = emulator.ram(0);
+    assert_eq!(ram[..8], inverted[..]);
+    assert_eq!(ram[8..16], glyph[..]);
+    assert_eq!(ram[16..24], inverted[..]);
+    assert_eq!(ram[24..32], glyph[..]);
+}
+
+#[test]
+fn escape_sequences_can_be_split() {
+    let text = "ab\x1b[2;3Hc\x1b[7md\x1b[1K";
+    let mut display = terminal(DisplaySize128x32, DisplayRotation::Rotate0);
+    for c in text.chars() {
+        block_on(display.print_char(c)).unwrap();
+    }
+
+    assert_eq!(display.release().display.frame(), render(text));
+}
+
+#[test]
+fn unsupported_sequences_are_ignored() {
+    assert_eq!(
+        render("\x1b[?25la\x1b(Bb\x1b[38;5;7mc\x1b[1;31md\x1bMe\x07f\x1b[2\x18g"),
+        render("abcdefg")
+    );
+}
+
+#[test]
+fn clear_lines_and_regions() {
+    let lines = "0123456789abcdef\
+                 0123456789abcdef\
+                 0123456789abcdef\
+                 0123456789abcde";
+    let clear = |f: &dyn Fn(&mut Ssd1306<CountingInterface, DisplaySize128x32, TerminalMode>)| {
+        let mut display = terminal(DisplaySize128x32, DisplayRotation::Rotate0);
+        block_on(display.write_str(lines)).unwrap();
+        block_on(display.set_position(4, 1)).unwrap();
+        f(&mut display);
+        assert_eq!(display.position().unwrap(), (4, 1));
+        display.release().display.frame()
+    };
+
+    assert_eq!(
+        clear(&|d| block_on(d.clear_line(2)).unwrap()),
+        render(&format!("{}\x1b[3;1H\x1b[2K", lines))
+    );
+    assert_eq!(
+        clear(&|d| block_on(d.clear_to_end_of_line()).unwrap()),
+        render(&format!("{}\x1b[2;5H\x1b[K", lines))
+    );
+    assert_eq!(
+        clear(&|d| block_on(d.clear_region(2, 1, 3, 2)).unwrap()),
+        render(
+            "0123456789abcdef\
+             01\x1b[3C56789abcdef\
+             01\x1b[3C56789abcdef\
+             0123456789abcde"
+        )
+    );
+    assert!(matches!(
+        block_on(terminal(DisplaySize128x32, DisplayRotation::Rotate0).clear_region(2, 1, 15, 1)),
+        Err(TerminalModeError::OutOfBounds)
+    ));
+}
+
+#[test]
+fn fill_region_sets_cell_bytes() {
+    for rotation in [DisplayRotation::Rotate0, DisplayRotation::Rotate90] {
+        let mut display = terminal(DisplaySize128x64, rotation);
+        block_on(display.set_position(1, 1)).unwrap();
+        block_on(display.fill_region(1, 2, 2, 3, 0xFF)).unwrap();
+        assert_eq!(display.position().unwrap(), (1, 1));
+
+        // Filled cells are 8x8 pixel squares
+        let frame = display.release().display.frame();
+        let (width, height) = (frame.width(), frame.height());
+        let (x, y) = match rotation {
+            DisplayRotation::Rotate0 => (8..24, 16..40),
+            _ => (width - 40..width - 16, 8..24),
+        };
+        for fy in 0..height {
+            for fx in 0..width {
+                assert_eq!(
+                    frame.pixel(fx, fy),
+                    x.contains(&fx) && y.contains(&fy),
+                    "{:?} ({}, {})",
+                    rotation,
+                    fx,
+                    fy
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn attributes_are_applied_to_glyphs() {
+    let mut display = terminal(DisplaySize128x32, DisplayRotation::Rotate0);
+    let underline = Attributes::new().underline(true);
+    let highlight = Attributes::new().inverse(true).strikethrough(true);
+
+    block_on(display.print_str_with(underline, "A")).unwrap();
+    block_on(display.print_str_with(highlight, "A\x1b[0mA")).unwrap();
+    assert_eq!(display.attributes(), Attributes::new());
+    display.set_attributes(highlight.underline(true));
+    block_on(display.write_str("A")).unwrap();
+
+    let glyph = Font8x8.glyph('A').unwrap();
+    let cell = |f: fn(u8) -> u8| glyph.iter().map(|&byte| f(byte)).collect::<Vec<_>>();
+    let emulator = display.release().display;
+    let ram = emulator.ram(0);
+    assert_eq!(ram[..8], cell(|byte| byte | 0x80)[..]);
+    assert_eq!(ram[8..16], cell(|byte| !(byte | 0x08))[..]);
+    assert_eq!(ram[16..24], glyph[..]);
+    assert_eq!(ram[24..32], cell(|byte| !(byte | 0x88))[..]);
+}
+
+/// Print text, show the cursor and toggle it `ticks` times, and get the resulting frame
+fn render_cursor(text: &str, style: CursorStyle, ticks: usize) -> Frame {
+    let mut display =
+        terminal(DisplaySize128x32, DisplayRotation::Rotate0).with_cells([TerminalCell::BLANK; 64]);
+    block_on(display.write_str(text)).unwrap();
+    let position = display.position().unwrap();
+
+    block_on(display.set_cursor_style(style)).unwrap();
+    for _ in 0..ticks {
+        block_on(display.blink_cursor_tick()).unwrap();
+    }
+
+    assert_eq!(display.cursor_style(), style);
+    assert_eq!(display.position().unwrap(), position);
+    display.release().display.frame()
+}
+
+#[test]
+fn cursor_is_drawn_over_cell() {
+    let text = "ab\x1b[D";
+    assert_eq!(
+        render_cursor(text, CursorStyle::Block, 0),
+        render("a\x1b[7mb")
+    );
+    assert_eq!(render_cursor(text, CursorStyle::Block, 1), render("ab"));
+    assert_eq!(
+        render_cursor(text, CursorStyle::Block, 2),
+        render("a\x1b[7mb")
+    );
+    assert_eq!(
+        render_cursor(text, CursorStyle::Underline, 0),
+        render("a\x1b[4mb")
+    );
+    assert_eq!(render_cursor(text, CursorStyle::Hidden, 0), render("ab"));
+    assert_eq!(render_cursor(text, CursorStyle::Hidden, 1), render("ab"));
+
+    // The cursor toggles the attributes of the character below it
+    assert_eq!(
+        render_cursor("\x1b[7;4mab\x1b[D", CursorStyle::Block, 0),
+        render("\x1b[7;4ma\x1b[27mb")
+    );
+    assert_eq!(
+        render_cursor("\x1b[7;4mab\x1b[D", CursorStyle::Underline, 0),
+        render("\x1b[7;4ma\x1b[24mb")
+    );
+}
+
+#[test]
+fn cursor_is_removed_when_printing() {
+    let mut display =
+        terminal(DisplaySize128x32, DisplayRotation::Rotate0).with_cells([TerminalCell::BLANK; 64]);
+    block_on(display.write_str("ab")).unwrap();
+    block_on(display.set_cursor_style(CursorStyle::Block)).unwrap();
+    block_on(display.write_str("c\nd")).unwrap();
+    block_on(display.set_position(1, 1)).unwrap();
+    block_on(display.print_char('e')).unwrap();
+    assert_eq!(display.release().display.frame(), render("abc\nde"));
+
+    // The cursor is shown at the new position by the next tick
+    assert_eq!(
+        render_cursor("abc\nde", CursorStyle::Block, 2),
+        render("abc\nde\x1b[7m ")
+    );
+}
+
+#[test]
+fn cursor_redraws_scrolled_and_filled_cells() {
+    assert_eq!(
+        render_cursor("1\n2\n3\n4\n5\x1b[2;1H", CursorStyle::Block, 0),
+        render("1\n2\n\x1b[7m3\x1b[0m\n4\n5")
+    );
+    assert_eq!(
+        render_cursor("1\n2\n3\n4\n5\x1b[2;1H", CursorStyle::Block, 1),
+        render("1\n2\n3\n4\n5")
+    );
+
+    let fill = |byte, style| {
+        let mut display = terminal(DisplaySize128x32, DisplayRotation::Rotate0)
+            .with_cells([TerminalCell::BLANK; 64]);
+        block_on(display.fill_region(0, 0, 2, 1, byte)).unwrap();
+        block_on(display.set_cursor_style(style)).unwrap();
+        display.release().display.ram(0)[..16].to_vec()
+    };
+    assert_eq!(fill(0x0F, CursorStyle::Underline)[..8], [0xF0; 8]);
+    assert_eq!(fill(0x0F, CursorStyle::Block)[8..], [0x0F; 8]);
+}
+
+#[test]
+fn cursor_is_only_shown_over_kept_cells() {
+    let mut display = terminal(DisplaySize128x32, DisplayRotation::Rotate0);
+    assert!(matches!(
+        block_on(display.set_cursor_style(CursorStyle::Block)),
+        Err(TerminalModeError::NoCellStorage)
+    ));
+    block_on(display.set_cursor_style(CursorStyle::Hidden)).unwrap();
+
+    // Only the first row is kept
+    let mut display = display.with_cells([TerminalCell::BLANK; 16]);
+    block_on(display.write_str("a\nb")).unwrap();
+    block_on(display.set_cursor_style(CursorStyle::Block)).unwrap();
+    block_on(display.set_position(0, 0)).unwrap();
+    block_on(display.blink_cursor_tick()).unwrap();
+    assert_eq!(
+        display.release().display.frame(),
+        render("\x1b[7ma\x1b[0m\nb")
+    );
+}
+
+/// Font with a glyph for `€` besides the glyphs of [`Font8x8`]
+struct EuroFont;
+
+impl TerminalFont for EuroFont {
+    const WIDTH: u8 = 8;
+    const PAGES: u8 = 1;
+
+    fn glyph(&self, c: char) -> Option<&[u8]> {
+        match c {
+            '€' => Some(&[0x14, 0x3e, 0x55, 0x55, 0x41, 0x22, 0x00, 0x00]),
+            _ => Font8x8.glyph(c),
+        }
+    }
+}
+
+#[test]
+fn cursor_redraws_characters_outside_latin1() {
+    let render = |style, ticks| {
+        let mut display = Ssd1306::new(
+            EmulatedDisplay::new(),
+            DisplaySize128x32,
+            DisplayRotation::Rotate0,
+        )
+        .into_terminal_mode_with_font(EuroFont)
+        .with_cells([TerminalCell::BLANK; 64]);
+        block_on(display.init()).unwrap();
+        block_on(display.write_str("€\x1b[D")).unwrap();
+        block_on(display.set_cursor_style(style)).unwrap();
+        for _ in 0..ticks {
+            block_on(display.blink_cursor_tick()).unwrap();
+        }
+        display.release().frame()
+    };
+
+    let text = render(CursorStyle::Hidden, 0);
+    assert_ne!(render(CursorStyle::Block, 0), text);
+    assert_eq!(render(CursorStyle::Block, 1), text);
+}
+
+#[test]
+fn scaled_text_enlarges_glyphs() {
+    for (scale, (factor_x, factor_y)) in [
+        (TextScale::X2, (2usize, 2usize)),
+        (TextScale::X4, (4, 4)),
+        (TextScale::DoubleWidth, (2, 1)),
+        (TextScale::DoubleHeight, (1, 2)),
+    ] {
+        let mut display = terminal(DisplaySize128x32, DisplayRotation::Rotate0);
+        block_on(display.set_text_scale(scale)).unwrap();
+        assert_eq!(display.text_scale(), scale);
+        assert_eq!(
+            display.char_dimensions(),
+            (16 / factor_x as u8, 4 / factor_y as u8)
+        );
+        block_on(display.write_str("A")).unwrap();
+        assert_eq!(display.position().unwrap(), (1, 0));
+
+        let frame = display.release().display.frame();
+        let expected = render("A");
+        for y in 0..32 {
+            for x in 0..128 {
+                let in_cell = x < 8 * factor_x && y < 8 * factor_y;
+                assert_eq!(
+                    frame.pixel(x, y),
+                    in_cell && expected.pixel(x / factor_x, y / factor_y),
+                    "{:?} ({}, {})",
+                    scale,
+                    x,
+                    y
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn scaled_text_uses_scaled_cells() {
+    let mut display = terminal(DisplaySize128x32, DisplayRotation::Rotate0);
+    block_on(display.write_str("small")).unwrap();
+    block_on(display.set_text_scale(TextScale::X2)).unwrap();
+    assert_eq!(display.position().unwrap(), (0, 0));
+
+    // Lines wrap after 8 characters and scroll by 16 pixels
+    block_on(display.write_str("0123456789\x1b[2;1H\x1b[K")).unwrap();
+    assert_eq!(display.position().unwrap(), (0, 1));
+    block_on(display.write_str("\n")).unwrap();
+    let emulator = display.release().display;
+    assert_eq!(emulator.start_line(), 16);
+
+    // Characters which don't fit on the display are rejected
+    let mut display = Ssd1306::new(
+        CountingInterface::default(),
+        DisplaySize128x32,
+        DisplayRotation::Rotate0,
+    )
+    .into_terminal_mode_with_font(Font8x16);
+    block_on(display.init()).unwrap();
+    assert!(matches!(
+        block_on(display.set_text_scale(TextScale::X4)),
+        Err(TerminalModeError::OutOfBounds)
+    ));
+    assert_eq!(display.text_scale(), TextScale::X1);
+    assert_eq!(display.char_dimensions(), (16, 2));
+}
+
+#[test]
+fn char_num_follows_font() {
+    assert_eq!(DisplaySize128x64::CHAR_NUM, 128);
+    assert_eq!(DisplaySize72x40::CHAR_NUM, 45);
+    assert_eq!(char_num::<DisplaySize128x64, Font8x8>(), 128);
+    assert_eq!(char_num::<DisplaySize128x64, Font5x8>(), 200);
+    assert_eq!(char_num::<DisplaySize128x32, Font6x8>(), 84);
+    assert_eq!(char_num::<DisplaySize72x40, Font8x16>(), 18);
+}
+
+#[test]
+fn custom_size_char_num() {
+    type DisplaySize80x48 = CustomSize<80, 48, 24>;
+
+    assert_eq!(DisplaySize80x48::CHAR_NUM, 60);
+    assert_eq!(char_num::<DisplaySize80x48, Font6x8>(), 78);
+    assert_eq!(char_num::<DisplaySize80x48, Font8x16>(), 30);
+}